@@ -0,0 +1,22 @@
+use crate::{
+	hct::Hct,
+	utils::{
+		color::Argb,
+		math::{difference_degrees, rotation_direction, sanitize_degrees_double},
+	},
+};
+
+//https://github.com/material-foundation/material-color-utilities/blob/main/typescript/blend/blend.ts
+
+/// Shifts the hue of `design_color` toward `source_color`, by half the
+/// difference and at most 15°, keeping its chroma and tone.
+pub fn harmonize(design_color: Argb, source_color: Argb) -> Argb {
+	let from_hct = Hct::from_argb(design_color);
+	let to_hct = Hct::from_argb(source_color);
+	let difference_degrees = difference_degrees(from_hct.hue(), to_hct.hue());
+	let rotation_degrees = (difference_degrees * 0.5).min(15.0);
+	let output_hue = sanitize_degrees_double(
+		from_hct.hue() + rotation_degrees * rotation_direction(from_hct.hue(), to_hct.hue()),
+	);
+	Hct::from(output_hue, from_hct.chroma(), from_hct.tone()).to_int()
+}
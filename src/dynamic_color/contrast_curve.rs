@@ -0,0 +1,55 @@
+use crate::utils::math::lerp;
+
+/// Contrast ratios a role should reach against its background, keyed by the
+/// contrast level of the [`DynamicScheme`](super::DynamicScheme).
+///
+/// `low`, `normal`, `medium` and `high` are the ratios at contrast levels
+/// -1.0, 0.0, 0.5 and 1.0, values in between are interpolated linearly.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ContrastCurve {
+	low: f64,
+	normal: f64,
+	medium: f64,
+	high: f64,
+}
+
+impl ContrastCurve {
+	pub fn new(low: f64, normal: f64, medium: f64, high: f64) -> Self {
+		Self {
+			low,
+			normal,
+			medium,
+			high,
+		}
+	}
+
+	pub fn low(&self) -> f64 {
+		self.low
+	}
+
+	pub fn normal(&self) -> f64 {
+		self.normal
+	}
+
+	pub fn medium(&self) -> f64 {
+		self.medium
+	}
+
+	pub fn high(&self) -> f64 {
+		self.high
+	}
+
+	pub fn get(&self, contrast_level: f64) -> f64 {
+		if contrast_level <= -1.0 {
+			self.low
+		} else if contrast_level < 0.0 {
+			lerp(self.low, self.normal, contrast_level + 1.0)
+		} else if contrast_level < 0.5 {
+			lerp(self.normal, self.medium, contrast_level / 0.5)
+		} else if contrast_level < 1.0 {
+			lerp(self.medium, self.high, (contrast_level - 0.5) / 0.5)
+		} else {
+			self.high
+		}
+	}
+}
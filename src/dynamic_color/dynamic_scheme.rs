@@ -0,0 +1,75 @@
+use crate::palettes::CorePalette;
+
+/// Revision of the Material spec whose tone tables a [`DynamicScheme`] follows.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum SpecVersion {
+	/// The original tables, as used by [`Scheme::light`](crate::scheme::Scheme::light).
+	#[default]
+	Spec2021,
+	/// Surfaces at tones 98 and 4 with darker surface containers in dark
+	/// schemes, and on-container roles at tone 30 in light schemes.
+	Spec2025,
+}
+
+/// Device family a [`DynamicScheme`] is generated for.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum Platform {
+	#[default]
+	Phone,
+	/// Wear OS, dark only and with black surfaces to save power on OLED screens.
+	Watch,
+}
+
+/// A [`CorePalette`] resolved for a given brightness and contrast level.
+///
+/// `contrast_level` goes from -1.0 (reduced) through 0.0 (standard) to 1.0 (high).
+#[derive(Clone, Debug, PartialEq)]
+pub struct DynamicScheme {
+	core: CorePalette,
+	is_dark: bool,
+	contrast_level: f64,
+	spec_version: SpecVersion,
+	platform: Platform,
+}
+
+impl DynamicScheme {
+	pub fn new(core: CorePalette, is_dark: bool, contrast_level: f64) -> Self {
+		Self {
+			core,
+			is_dark,
+			contrast_level: contrast_level.clamp(-1.0, 1.0),
+			spec_version: SpecVersion::default(),
+			platform: Platform::default(),
+		}
+	}
+
+	pub fn with_spec_version(mut self, spec_version: SpecVersion) -> Self {
+		self.spec_version = spec_version;
+		self
+	}
+
+	pub fn with_platform(mut self, platform: Platform) -> Self {
+		self.platform = platform;
+		self
+	}
+
+	pub fn core(&mut self) -> &mut CorePalette {
+		&mut self.core
+	}
+
+	pub fn is_dark(&self) -> bool {
+		self.is_dark || self.platform == Platform::Watch
+	}
+
+	pub fn contrast_level(&self) -> f64 {
+		self.contrast_level
+	}
+
+	pub fn spec_version(&self) -> SpecVersion {
+		self.spec_version
+	}
+
+	pub fn platform(&self) -> Platform {
+		self.platform
+	}
+}
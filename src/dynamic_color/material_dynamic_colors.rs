@@ -0,0 +1,513 @@
+use super::{
+	ContrastCurve, DynamicColor, DynamicScheme, Platform, SpecVersion, ToneDeltaPair, TonePolarity,
+};
+use crate::palettes::CorePalette;
+
+/// The Material color roles as [`DynamicColor`]s, with tones following the
+/// [`SpecVersion`] and [`Platform`] of the scheme they are resolved in.
+///
+/// Containers keep their tone at every contrast level, it is the on-colors
+/// and the paired accent roles that move to reach the ratios asked for.
+pub struct MaterialDynamicColors;
+
+impl MaterialDynamicColors {
+	pub fn background() -> DynamicColor {
+		DynamicColor::new(
+			"background",
+			CorePalette::n1,
+			surface_tone,
+			true,
+			None,
+			None,
+			None,
+		)
+	}
+
+	pub fn on_background() -> DynamicColor {
+		DynamicColor::new(
+			"on_background",
+			CorePalette::n1,
+			|s| if s.is_dark() { 90.0 } else { 10.0 },
+			false,
+			Some(|_| Self::background()),
+			Some(ContrastCurve::new(3.0, 3.0, 4.5, 7.0)),
+			None,
+		)
+	}
+
+	pub fn surface() -> DynamicColor {
+		DynamicColor::new(
+			"surface",
+			CorePalette::n1,
+			surface_tone,
+			true,
+			None,
+			None,
+			None,
+		)
+	}
+
+	pub fn on_surface() -> DynamicColor {
+		DynamicColor::new(
+			"on_surface",
+			CorePalette::n1,
+			|s| if s.is_dark() { 90.0 } else { 10.0 },
+			false,
+			Some(|_| Self::surface()),
+			Some(ContrastCurve::new(4.5, 7.0, 11.0, 21.0)),
+			None,
+		)
+	}
+
+	pub fn surface_dim() -> DynamicColor {
+		DynamicColor::new(
+			"surface_dim",
+			CorePalette::n1,
+			|s| surface_role_tone(s, (87.0, 6.0), (87.0, 4.0), 0.0),
+			true,
+			None,
+			None,
+			None,
+		)
+	}
+
+	pub fn surface_bright() -> DynamicColor {
+		DynamicColor::new(
+			"surface_bright",
+			CorePalette::n1,
+			|s| surface_role_tone(s, (98.0, 24.0), (98.0, 18.0), 18.0),
+			true,
+			None,
+			None,
+			None,
+		)
+	}
+
+	pub fn surface_container_lowest() -> DynamicColor {
+		DynamicColor::new(
+			"surface_container_lowest",
+			CorePalette::n1,
+			|s| surface_role_tone(s, (100.0, 4.0), (100.0, 0.0), 0.0),
+			true,
+			None,
+			None,
+			None,
+		)
+	}
+
+	pub fn surface_container_low() -> DynamicColor {
+		DynamicColor::new(
+			"surface_container_low",
+			CorePalette::n1,
+			|s| surface_role_tone(s, (96.0, 10.0), (96.0, 6.0), 6.0),
+			true,
+			None,
+			None,
+			None,
+		)
+	}
+
+	pub fn surface_container() -> DynamicColor {
+		DynamicColor::new(
+			"surface_container",
+			CorePalette::n1,
+			|s| surface_role_tone(s, (94.0, 12.0), (94.0, 9.0), 10.0),
+			true,
+			None,
+			None,
+			None,
+		)
+	}
+
+	pub fn surface_container_high() -> DynamicColor {
+		DynamicColor::new(
+			"surface_container_high",
+			CorePalette::n1,
+			|s| surface_role_tone(s, (92.0, 17.0), (92.0, 12.0), 14.0),
+			true,
+			None,
+			None,
+			None,
+		)
+	}
+
+	pub fn surface_container_highest() -> DynamicColor {
+		DynamicColor::new(
+			"surface_container_highest",
+			CorePalette::n1,
+			|s| surface_role_tone(s, (90.0, 22.0), (90.0, 15.0), 18.0),
+			true,
+			None,
+			None,
+			None,
+		)
+	}
+
+	pub fn surface_variant() -> DynamicColor {
+		DynamicColor::new(
+			"surface_variant",
+			CorePalette::n2,
+			|s| match s.spec_version() {
+				SpecVersion::Spec2021 if s.is_dark() => 30.0,
+				SpecVersion::Spec2021 => 90.0,
+				// Same tone as surface_container_highest.
+				SpecVersion::Spec2025 => surface_role_tone(s, (90.0, 22.0), (90.0, 15.0), 18.0),
+			},
+			true,
+			None,
+			None,
+			None,
+		)
+	}
+
+	pub fn on_surface_variant() -> DynamicColor {
+		DynamicColor::new(
+			"on_surface_variant",
+			CorePalette::n2,
+			|s| if s.is_dark() { 80.0 } else { 30.0 },
+			false,
+			Some(|_| Self::surface_variant()),
+			Some(ContrastCurve::new(3.0, 4.5, 7.0, 11.0)),
+			None,
+		)
+	}
+
+	pub fn outline() -> DynamicColor {
+		DynamicColor::new(
+			"outline",
+			CorePalette::n2,
+			|s| if s.is_dark() { 60.0 } else { 50.0 },
+			false,
+			Some(|_| Self::surface()),
+			Some(ContrastCurve::new(1.5, 3.0, 4.5, 7.0)),
+			None,
+		)
+	}
+
+	pub fn outline_variant() -> DynamicColor {
+		DynamicColor::new(
+			"outline_variant",
+			CorePalette::n2,
+			|s| if s.is_dark() { 30.0 } else { 80.0 },
+			false,
+			Some(|_| Self::surface()),
+			Some(ContrastCurve::new(1.0, 1.0, 3.0, 4.5)),
+			None,
+		)
+	}
+
+	pub fn shadow() -> DynamicColor {
+		DynamicColor::new("shadow", CorePalette::n1, |_| 0.0, false, None, None, None)
+	}
+
+	pub fn scrim() -> DynamicColor {
+		DynamicColor::new("scrim", CorePalette::n1, |_| 0.0, false, None, None, None)
+	}
+
+	pub fn inverse_surface() -> DynamicColor {
+		DynamicColor::new(
+			"inverse_surface",
+			CorePalette::n1,
+			|s| if s.is_dark() { 90.0 } else { 20.0 },
+			true,
+			None,
+			None,
+			None,
+		)
+	}
+
+	pub fn inverse_on_surface() -> DynamicColor {
+		DynamicColor::new(
+			"inverse_on_surface",
+			CorePalette::n1,
+			|s| if s.is_dark() { 20.0 } else { 95.0 },
+			false,
+			Some(|_| Self::inverse_surface()),
+			Some(ContrastCurve::new(4.5, 7.0, 11.0, 21.0)),
+			None,
+		)
+	}
+
+	pub fn inverse_primary() -> DynamicColor {
+		DynamicColor::new(
+			"inverse_primary",
+			CorePalette::a1,
+			|s| if s.is_dark() { 40.0 } else { 80.0 },
+			false,
+			Some(|_| Self::inverse_surface()),
+			Some(ContrastCurve::new(3.0, 4.5, 7.0, 7.0)),
+			None,
+		)
+	}
+
+	pub fn primary() -> DynamicColor {
+		DynamicColor::new(
+			"primary",
+			CorePalette::a1,
+			|s| if s.is_dark() { 80.0 } else { 40.0 },
+			true,
+			Some(|_| Self::surface()),
+			Some(ContrastCurve::new(3.0, 4.5, 7.0, 7.0)),
+			Some(|_| Self::primary_pair()),
+		)
+	}
+
+	pub fn on_primary() -> DynamicColor {
+		DynamicColor::new(
+			"on_primary",
+			CorePalette::a1,
+			|s| if s.is_dark() { 20.0 } else { 100.0 },
+			false,
+			Some(|_| Self::primary()),
+			Some(ContrastCurve::new(4.5, 7.0, 11.0, 21.0)),
+			None,
+		)
+	}
+
+	pub fn primary_container() -> DynamicColor {
+		DynamicColor::new(
+			"primary_container",
+			CorePalette::a1,
+			|s| if s.is_dark() { 30.0 } else { 90.0 },
+			true,
+			Some(|_| Self::surface()),
+			None,
+			Some(|_| Self::primary_pair()),
+		)
+	}
+
+	pub fn on_primary_container() -> DynamicColor {
+		DynamicColor::new(
+			"on_primary_container",
+			CorePalette::a1,
+			on_container_tone,
+			false,
+			Some(|_| Self::primary_container()),
+			Some(ContrastCurve::new(4.5, 7.0, 11.0, 21.0)),
+			None,
+		)
+	}
+
+	pub fn secondary() -> DynamicColor {
+		DynamicColor::new(
+			"secondary",
+			CorePalette::a2,
+			|s| if s.is_dark() { 80.0 } else { 40.0 },
+			true,
+			Some(|_| Self::surface()),
+			Some(ContrastCurve::new(3.0, 4.5, 7.0, 7.0)),
+			Some(|_| Self::secondary_pair()),
+		)
+	}
+
+	pub fn on_secondary() -> DynamicColor {
+		DynamicColor::new(
+			"on_secondary",
+			CorePalette::a2,
+			|s| if s.is_dark() { 20.0 } else { 100.0 },
+			false,
+			Some(|_| Self::secondary()),
+			Some(ContrastCurve::new(4.5, 7.0, 11.0, 21.0)),
+			None,
+		)
+	}
+
+	pub fn secondary_container() -> DynamicColor {
+		DynamicColor::new(
+			"secondary_container",
+			CorePalette::a2,
+			|s| if s.is_dark() { 30.0 } else { 90.0 },
+			true,
+			Some(|_| Self::surface()),
+			None,
+			Some(|_| Self::secondary_pair()),
+		)
+	}
+
+	pub fn on_secondary_container() -> DynamicColor {
+		DynamicColor::new(
+			"on_secondary_container",
+			CorePalette::a2,
+			on_container_tone,
+			false,
+			Some(|_| Self::secondary_container()),
+			Some(ContrastCurve::new(4.5, 7.0, 11.0, 21.0)),
+			None,
+		)
+	}
+
+	pub fn tertiary() -> DynamicColor {
+		DynamicColor::new(
+			"tertiary",
+			CorePalette::a3,
+			|s| if s.is_dark() { 80.0 } else { 40.0 },
+			true,
+			Some(|_| Self::surface()),
+			Some(ContrastCurve::new(3.0, 4.5, 7.0, 7.0)),
+			Some(|_| Self::tertiary_pair()),
+		)
+	}
+
+	pub fn on_tertiary() -> DynamicColor {
+		DynamicColor::new(
+			"on_tertiary",
+			CorePalette::a3,
+			|s| if s.is_dark() { 20.0 } else { 100.0 },
+			false,
+			Some(|_| Self::tertiary()),
+			Some(ContrastCurve::new(4.5, 7.0, 11.0, 21.0)),
+			None,
+		)
+	}
+
+	pub fn tertiary_container() -> DynamicColor {
+		DynamicColor::new(
+			"tertiary_container",
+			CorePalette::a3,
+			|s| if s.is_dark() { 30.0 } else { 90.0 },
+			true,
+			Some(|_| Self::surface()),
+			None,
+			Some(|_| Self::tertiary_pair()),
+		)
+	}
+
+	pub fn on_tertiary_container() -> DynamicColor {
+		DynamicColor::new(
+			"on_tertiary_container",
+			CorePalette::a3,
+			on_container_tone,
+			false,
+			Some(|_| Self::tertiary_container()),
+			Some(ContrastCurve::new(4.5, 7.0, 11.0, 21.0)),
+			None,
+		)
+	}
+
+	pub fn error() -> DynamicColor {
+		DynamicColor::new(
+			"error",
+			CorePalette::error,
+			|s| if s.is_dark() { 80.0 } else { 40.0 },
+			true,
+			Some(|_| Self::surface()),
+			Some(ContrastCurve::new(3.0, 4.5, 7.0, 7.0)),
+			Some(|_| Self::error_pair()),
+		)
+	}
+
+	pub fn on_error() -> DynamicColor {
+		DynamicColor::new(
+			"on_error",
+			CorePalette::error,
+			|s| if s.is_dark() { 20.0 } else { 100.0 },
+			false,
+			Some(|_| Self::error()),
+			Some(ContrastCurve::new(4.5, 7.0, 11.0, 21.0)),
+			None,
+		)
+	}
+
+	pub fn error_container() -> DynamicColor {
+		DynamicColor::new(
+			"error_container",
+			CorePalette::error,
+			|s| if s.is_dark() { 30.0 } else { 90.0 },
+			true,
+			Some(|_| Self::surface()),
+			None,
+			Some(|_| Self::error_pair()),
+		)
+	}
+
+	pub fn on_error_container() -> DynamicColor {
+		DynamicColor::new(
+			"on_error_container",
+			CorePalette::error,
+			|s| match s.spec_version() {
+				SpecVersion::Spec2021 if s.is_dark() => 80.0,
+				_ => on_container_tone(s),
+			},
+			false,
+			Some(|_| Self::error_container()),
+			// The 2021 dark tone of 80 only reaches ~5.5:1 over its container.
+			Some(ContrastCurve::new(3.0, 4.5, 11.0, 21.0)),
+			None,
+		)
+	}
+
+	fn primary_pair() -> ToneDeltaPair {
+		ToneDeltaPair::new(
+			Self::primary_container(),
+			Self::primary(),
+			10.0,
+			TonePolarity::Nearer,
+			false,
+		)
+	}
+
+	fn secondary_pair() -> ToneDeltaPair {
+		ToneDeltaPair::new(
+			Self::secondary_container(),
+			Self::secondary(),
+			10.0,
+			TonePolarity::Nearer,
+			false,
+		)
+	}
+
+	fn tertiary_pair() -> ToneDeltaPair {
+		ToneDeltaPair::new(
+			Self::tertiary_container(),
+			Self::tertiary(),
+			10.0,
+			TonePolarity::Nearer,
+			false,
+		)
+	}
+
+	fn error_pair() -> ToneDeltaPair {
+		ToneDeltaPair::new(
+			Self::error_container(),
+			Self::error(),
+			10.0,
+			TonePolarity::Nearer,
+			false,
+		)
+	}
+}
+
+fn surface_tone(s: &DynamicScheme) -> f64 {
+	surface_role_tone(s, (99.0, 10.0), (98.0, 4.0), 0.0)
+}
+
+/// Tone of a surface role from its light and dark tones in each spec, or its
+/// tone on a watch.
+fn surface_role_tone(
+	s: &DynamicScheme,
+	spec_2021: (f64, f64),
+	spec_2025: (f64, f64),
+	watch: f64,
+) -> f64 {
+	if s.platform() == Platform::Watch {
+		return watch;
+	}
+
+	let (light, dark) = match s.spec_version() {
+		SpecVersion::Spec2021 => spec_2021,
+		SpecVersion::Spec2025 => spec_2025,
+	};
+	if s.is_dark() {
+		dark
+	} else {
+		light
+	}
+}
+
+fn on_container_tone(s: &DynamicScheme) -> f64 {
+	match (s.spec_version(), s.is_dark()) {
+		(_, true) => 90.0,
+		(SpecVersion::Spec2021, false) => 10.0,
+		(SpecVersion::Spec2025, false) => 30.0,
+	}
+}
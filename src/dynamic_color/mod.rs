@@ -0,0 +1,193 @@
+use crate::{
+	palettes::{CorePalette, TonalPalette},
+	utils::{
+		color::Argb,
+		contrast::{darker_unsafe, lighter_unsafe, ratio_of_tones},
+	},
+};
+
+pub mod contrast_curve;
+pub mod dynamic_scheme;
+pub mod material_dynamic_colors;
+pub mod tone_delta_pair;
+
+pub use contrast_curve::ContrastCurve;
+pub use dynamic_scheme::{DynamicScheme, Platform, SpecVersion};
+pub use material_dynamic_colors::MaterialDynamicColors;
+pub use tone_delta_pair::{ToneDeltaPair, TonePolarity};
+
+/// A color role whose tone depends on the [`DynamicScheme`] it is resolved in.
+///
+/// `tone` gives the standard tone of the role. When a `background` and a
+/// `contrast_curve` are set, the tone is moved away from the background until
+/// it reaches the ratio the curve asks for at the scheme contrast level, or
+/// towards it when the contrast level is negative. Roles in a `tone_delta_pair` are resolved together
+/// so they never collapse onto the same tone.
+#[derive(Clone, Copy)]
+pub struct DynamicColor {
+	name: &'static str,
+	palette: fn(&mut CorePalette) -> &mut TonalPalette,
+	tone: fn(&DynamicScheme) -> f64,
+	is_background: bool,
+	background: Option<fn(&DynamicScheme) -> DynamicColor>,
+	contrast_curve: Option<ContrastCurve>,
+	tone_delta_pair: Option<fn(&DynamicScheme) -> ToneDeltaPair>,
+}
+
+impl DynamicColor {
+	pub fn new(
+		name: &'static str,
+		palette: fn(&mut CorePalette) -> &mut TonalPalette,
+		tone: fn(&DynamicScheme) -> f64,
+		is_background: bool,
+		background: Option<fn(&DynamicScheme) -> DynamicColor>,
+		contrast_curve: Option<ContrastCurve>,
+		tone_delta_pair: Option<fn(&DynamicScheme) -> ToneDeltaPair>,
+	) -> Self {
+		Self {
+			name,
+			palette,
+			tone,
+			is_background,
+			background,
+			contrast_curve,
+			tone_delta_pair,
+		}
+	}
+
+	pub fn name(&self) -> &'static str {
+		self.name
+	}
+
+	pub fn is_background(&self) -> bool {
+		self.is_background
+	}
+
+	pub fn get_argb(&self, scheme: &mut DynamicScheme) -> Argb {
+		let tone = self.get_tone(scheme);
+		(self.palette)(scheme.core()).tone(tone.round() as u8)
+	}
+
+	pub fn get_tone(&self, scheme: &DynamicScheme) -> f64 {
+		let background = match self.background {
+			Some(background) => background(scheme),
+			None => return (self.tone)(scheme),
+		};
+		let bg_tone = background.get_tone(scheme);
+
+		match self.tone_delta_pair {
+			Some(tone_delta_pair) => self.paired_tone(tone_delta_pair(scheme), bg_tone, scheme),
+			None => self.standalone_tone(bg_tone, scheme),
+		}
+	}
+
+	fn standalone_tone(&self, bg_tone: f64, scheme: &DynamicScheme) -> f64 {
+		let tone = (self.tone)(scheme);
+		match self.contrast_curve {
+			Some(curve) => {
+				let contrast_level = scheme.contrast_level();
+				foreground_tone(
+					tone,
+					bg_tone,
+					curve.get(contrast_level),
+					contrast_level < 0.0,
+				)
+			}
+			None => tone,
+		}
+	}
+
+	fn paired_tone(&self, pair: ToneDeltaPair, bg_tone: f64, scheme: &DynamicScheme) -> f64 {
+		let delta = pair.delta();
+		let a_is_nearer = match pair.polarity() {
+			TonePolarity::Nearer => true,
+			TonePolarity::Farther => false,
+			TonePolarity::Lighter => !scheme.is_dark(),
+			TonePolarity::Darker => scheme.is_dark(),
+		};
+		let (nearer, farther) = if a_is_nearer {
+			(pair.role_a(), pair.role_b())
+		} else {
+			(pair.role_b(), pair.role_a())
+		};
+		let am_nearer = self.name == nearer.name;
+		let expansion_dir = if scheme.is_dark() { 1.0 } else { -1.0 };
+
+		let mut n_tone = nearer.standalone_tone(bg_tone, scheme);
+		let mut f_tone = farther.standalone_tone(bg_tone, scheme);
+
+		// Push the farther role away first, and only pull the nearer one back
+		// when the farther one already hit the end of the tone range.
+		if (f_tone - n_tone) * expansion_dir < delta {
+			f_tone = (n_tone + delta * expansion_dir).clamp(0.0, 100.0);
+			if (f_tone - n_tone) * expansion_dir < delta {
+				n_tone = (f_tone - delta * expansion_dir).clamp(0.0, 100.0);
+			}
+		}
+
+		// Tones 50-59 are avoided since they barely contrast with either
+		// black or white.
+		if (50.0..60.0).contains(&n_tone) {
+			if expansion_dir > 0.0 {
+				n_tone = 60.0;
+				f_tone = f_tone.max(n_tone + delta * expansion_dir);
+			} else {
+				n_tone = 49.0;
+				f_tone = f_tone.min(n_tone + delta * expansion_dir);
+			}
+		} else if (50.0..60.0).contains(&f_tone) {
+			if pair.stay_together() {
+				if expansion_dir > 0.0 {
+					n_tone = 60.0;
+					f_tone = f_tone.max(n_tone + delta * expansion_dir);
+				} else {
+					n_tone = 49.0;
+					f_tone = f_tone.min(n_tone + delta * expansion_dir);
+				}
+			} else if expansion_dir > 0.0 {
+				f_tone = 60.0;
+			} else {
+				f_tone = 49.0;
+			}
+		}
+
+		if am_nearer {
+			n_tone
+		} else {
+			f_tone
+		}
+	}
+}
+
+impl std::fmt::Debug for DynamicColor {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		f.debug_struct("DynamicColor")
+			.field("name", &self.name)
+			.field("is_background", &self.is_background)
+			.field("contrast_curve", &self.contrast_curve)
+			.finish()
+	}
+}
+
+// Moves `tone` away from `bg_tone`, keeping the side it is on, until `ratio` is
+// reached. When `decreasing` it may also move closer to `bg_tone`, down to
+// `ratio`. Tones are snapped to whole values outwards so the ratio still holds
+// once looked up in a tonal palette.
+fn foreground_tone(tone: f64, bg_tone: f64, ratio: f64, decreasing: bool) -> f64 {
+	let current_ratio = ratio_of_tones(tone, bg_tone);
+	if current_ratio == ratio || (current_ratio > ratio && !decreasing) {
+		return tone;
+	}
+
+	let candidate = if tone >= bg_tone {
+		lighter_unsafe(bg_tone, ratio).ceil().min(100.0)
+	} else {
+		darker_unsafe(bg_tone, ratio).floor().max(0.0)
+	};
+
+	if decreasing && ratio_of_tones(candidate, bg_tone) > current_ratio {
+		tone
+	} else {
+		candidate
+	}
+}
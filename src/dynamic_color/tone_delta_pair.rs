@@ -0,0 +1,65 @@
+use super::DynamicColor;
+
+/// Which role of a [`ToneDeltaPair`] has to end up closer to the background.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TonePolarity {
+	/// `role_a` is darker than `role_b`.
+	Darker,
+	/// `role_a` is lighter than `role_b`.
+	Lighter,
+	/// `role_a` is closer in tone to the background than `role_b`.
+	Nearer,
+	/// `role_a` is further in tone from the background than `role_b`.
+	Farther,
+}
+
+/// Keeps two roles sharing a background at least `delta` tones apart.
+///
+/// When `stay_together` is set and one of the roles has to leave the 50-59
+/// tone band, the other one follows it to the same side.
+#[derive(Clone, Copy, Debug)]
+pub struct ToneDeltaPair {
+	role_a: DynamicColor,
+	role_b: DynamicColor,
+	delta: f64,
+	polarity: TonePolarity,
+	stay_together: bool,
+}
+
+impl ToneDeltaPair {
+	pub fn new(
+		role_a: DynamicColor,
+		role_b: DynamicColor,
+		delta: f64,
+		polarity: TonePolarity,
+		stay_together: bool,
+	) -> Self {
+		Self {
+			role_a,
+			role_b,
+			delta,
+			polarity,
+			stay_together,
+		}
+	}
+
+	pub fn role_a(&self) -> DynamicColor {
+		self.role_a
+	}
+
+	pub fn role_b(&self) -> DynamicColor {
+		self.role_b
+	}
+
+	pub fn delta(&self) -> f64 {
+		self.delta
+	}
+
+	pub fn polarity(&self) -> TonePolarity {
+		self.polarity
+	}
+
+	pub fn stay_together(&self) -> bool {
+		self.stay_together
+	}
+}
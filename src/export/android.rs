@@ -0,0 +1,105 @@
+use crate::scheme::Scheme;
+use std::fmt::Write;
+
+/// Writes a light and dark [`Scheme`] as Android resources.
+///
+/// Colors are named `md_theme_{role}` in both `values/colors.xml` and
+/// `values-night/colors.xml`, so the night qualifier switches between them,
+/// and a DayNight theme maps them onto the Material3 theme attributes.
+#[derive(Clone, Debug)]
+pub struct AndroidExporter {
+	light: Scheme,
+	dark: Scheme,
+	theme_name: String,
+}
+
+impl AndroidExporter {
+	pub fn new(light: &Scheme, dark: &Scheme) -> Self {
+		Self {
+			light: light.clone(),
+			dark: dark.clone(),
+			theme_name: "AppTheme".to_string(),
+		}
+	}
+
+	/// Name of the generated `<style>`, `AppTheme` by default.
+	pub fn with_theme_name(mut self, theme_name: &str) -> Self {
+		self.theme_name = theme_name.to_string();
+		self
+	}
+
+	/// Contents of `values/colors.xml`, or `values-night/colors.xml` when
+	/// `is_dark`.
+	pub fn colors(&self, is_dark: bool) -> String {
+		let scheme = if is_dark { &self.dark } else { &self.light };
+		let mut xml = String::from("<?xml version=\"1.0\" encoding=\"utf-8\"?>\n<resources>\n");
+		for (role, argb) in scheme.roles() {
+			let _ = writeln!(xml, "    <color name=\"md_theme_{role}\">{argb}</color>");
+		}
+		xml.push_str("</resources>\n");
+		xml
+	}
+
+	/// Contents of `values/themes.xml`.
+	pub fn theme(&self) -> String {
+		let mut xml = String::from("<?xml version=\"1.0\" encoding=\"utf-8\"?>\n<resources>\n");
+		let _ = writeln!(
+			xml,
+			"    <style name=\"{}\" parent=\"Theme.Material3.DayNight.NoActionBar\">",
+			self.theme_name
+		);
+		for role in Scheme::ROLE_NAMES {
+			if let Some(attribute) = theme_attribute(role) {
+				let _ = writeln!(
+					xml,
+					"        <item name=\"{attribute}\">@color/md_theme_{role}</item>"
+				);
+			}
+		}
+		xml.push_str("    </style>\n</resources>\n");
+		xml
+	}
+
+	/// Every file with its path relative to `res/`.
+	pub fn files(&self) -> [(&'static str, String); 3] {
+		[
+			("values/colors.xml", self.colors(false)),
+			("values-night/colors.xml", self.colors(true)),
+			("values/themes.xml", self.theme()),
+		]
+	}
+}
+
+/// Material3 theme attribute of a role. `shadow` and `scrim` have none.
+fn theme_attribute(role: &str) -> Option<&'static str> {
+	Some(match role {
+		"primary" => "colorPrimary",
+		"on_primary" => "colorOnPrimary",
+		"primary_container" => "colorPrimaryContainer",
+		"on_primary_container" => "colorOnPrimaryContainer",
+		"secondary" => "colorSecondary",
+		"on_secondary" => "colorOnSecondary",
+		"secondary_container" => "colorSecondaryContainer",
+		"on_secondary_container" => "colorOnSecondaryContainer",
+		"tertiary" => "colorTertiary",
+		"on_tertiary" => "colorOnTertiary",
+		"tertiary_container" => "colorTertiaryContainer",
+		"on_tertiary_container" => "colorOnTertiaryContainer",
+		"error" => "colorError",
+		"on_error" => "colorOnError",
+		"error_container" => "colorErrorContainer",
+		"on_error_container" => "colorOnErrorContainer",
+		"background" => "android:colorBackground",
+		"on_background" => "colorOnBackground",
+		"surface" => "colorSurface",
+		"on_surface" => "colorOnSurface",
+		"surface_variant" => "colorSurfaceVariant",
+		"on_surface_variant" => "colorOnSurfaceVariant",
+		"outline" => "colorOutline",
+		"outline_variant" => "colorOutlineVariant",
+		"inverse_surface" => "colorSurfaceInverse",
+		"inverse_on_surface" => "colorOnSurfaceInverse",
+		"inverse_primary" => "colorPrimaryInverse",
+		_ => return None,
+	})
+}
@@ -0,0 +1,119 @@
+use super::{camel_case, surface_container_roles};
+use crate::{dynamic_color::DynamicScheme, scheme::Scheme, utils::color::Argb};
+use std::fmt::Write;
+
+/// Generates Jetpack Compose `Color.kt` and `Theme.kt` sources from a light
+/// and dark scheme.
+///
+/// Colors are named `{role}Light` and `{role}Dark`. Schemes built from
+/// [`DynamicScheme`]s also get the surface container roles.
+#[derive(Clone, Debug)]
+pub struct ComposeExporter {
+	light: Vec<(&'static str, Argb)>,
+	dark: Vec<(&'static str, Argb)>,
+	package: String,
+	theme_name: String,
+}
+
+impl ComposeExporter {
+	pub fn new(light: &Scheme, dark: &Scheme) -> Self {
+		Self::from_roles(light.roles().to_vec(), dark.roles().to_vec())
+	}
+
+	pub fn from_dynamic_schemes(light: &mut DynamicScheme, dark: &mut DynamicScheme) -> Self {
+		let roles = |scheme: &mut DynamicScheme| {
+			let mut roles = Scheme::from_dynamic_scheme(scheme).roles().to_vec();
+			roles.extend(surface_container_roles(scheme));
+			roles
+		};
+		Self::from_roles(roles(light), roles(dark))
+	}
+
+	fn from_roles(light: Vec<(&'static str, Argb)>, dark: Vec<(&'static str, Argb)>) -> Self {
+		Self {
+			light,
+			dark,
+			package: "com.example.ui.theme".to_string(),
+			theme_name: "AppTheme".to_string(),
+		}
+	}
+
+	/// Kotlin package of both files, `com.example.ui.theme` by default.
+	pub fn with_package(mut self, package: &str) -> Self {
+		self.package = package.to_string();
+		self
+	}
+
+	/// Name of the generated `@Composable`, `AppTheme` by default.
+	pub fn with_theme_name(mut self, theme_name: &str) -> Self {
+		self.theme_name = theme_name.to_string();
+		self
+	}
+
+	pub fn color_kt(&self) -> String {
+		let mut kt = format!(
+			"package {}\n\nimport androidx.compose.ui.graphics.Color\n",
+			self.package
+		);
+		for (roles, variant) in [(&self.light, "Light"), (&self.dark, "Dark")] {
+			kt.push('\n');
+			for (role, argb) in roles.iter().filter(|(role, _)| in_color_scheme(role)) {
+				let _ = writeln!(
+					kt,
+					"val {}{variant} = Color(0x{argb:08X})",
+					camel_case(role)
+				);
+			}
+		}
+		kt
+	}
+
+	pub fn theme_kt(&self) -> String {
+		let mut kt = format!("package {}\n\n", self.package);
+		kt.push_str(
+			"import androidx.compose.foundation.isSystemInDarkTheme\n\
+			 import androidx.compose.material3.MaterialTheme\n\
+			 import androidx.compose.material3.darkColorScheme\n\
+			 import androidx.compose.material3.lightColorScheme\n\
+			 import androidx.compose.runtime.Composable\n",
+		);
+		for (roles, variant) in [(&self.light, "Light"), (&self.dark, "Dark")] {
+			let _ = writeln!(
+				kt,
+				"\nprivate val {}Scheme = {}ColorScheme(",
+				variant.to_lowercase(),
+				variant.to_lowercase()
+			);
+			for (role, _) in roles.iter().filter(|(role, _)| in_color_scheme(role)) {
+				let role = camel_case(role);
+				let _ = writeln!(kt, "    {role} = {role}{variant},");
+			}
+			kt.push_str(")\n");
+		}
+		let _ = write!(
+			kt,
+			"\n@Composable\n\
+			 fun {}(\n    \
+			     darkTheme: Boolean = isSystemInDarkTheme(),\n    \
+			     content: @Composable () -> Unit,\n\
+			 ) {{\n    \
+			     MaterialTheme(\n        \
+			         colorScheme = if (darkTheme) darkScheme else lightScheme,\n        \
+			         content = content,\n    \
+			     )\n\
+			 }}\n",
+			self.theme_name
+		);
+		kt
+	}
+
+	/// Both files with their names.
+	pub fn files(&self) -> [(&'static str, String); 2] {
+		[("Color.kt", self.color_kt()), ("Theme.kt", self.theme_kt())]
+	}
+}
+
+/// Compose's `ColorScheme` has no `shadow`.
+fn in_color_scheme(role: &str) -> bool {
+	role != "shadow"
+}
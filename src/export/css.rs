@@ -0,0 +1,81 @@
+use super::{kebab_case, PALETTES, PALETTE_TONES};
+use crate::{palettes::CorePalette, scheme::Scheme, utils::string::css_from_argb};
+use std::fmt::Write;
+
+/// Writes a light and dark [`Scheme`] as CSS custom properties.
+///
+/// The light scheme goes on `:root` and the dark one under
+/// `@media (prefers-color-scheme: dark)`, as `--md-sys-color-{role}`.
+#[derive(Clone, Debug)]
+pub struct CssExporter {
+	light: Scheme,
+	dark: Scheme,
+	palette: Option<CorePalette>,
+	data_theme: bool,
+}
+
+impl CssExporter {
+	pub fn new(light: &Scheme, dark: &Scheme) -> Self {
+		Self {
+			light: light.clone(),
+			dark: dark.clone(),
+			palette: None,
+			data_theme: false,
+		}
+	}
+
+	/// Also writes `--md-ref-palette-{palette}{tone}` tokens on `:root`.
+	pub fn with_palette(mut self, palette: &CorePalette) -> Self {
+		self.palette = Some(palette.clone());
+		self
+	}
+
+	/// Also writes `[data-theme="light"]` and `[data-theme="dark"]` blocks,
+	/// which take precedence over the media query.
+	pub fn with_data_theme(mut self, data_theme: bool) -> Self {
+		self.data_theme = data_theme;
+		self
+	}
+
+	pub fn export(&self) -> String {
+		let mut css = String::new();
+
+		css.push_str(":root {\n");
+		if let Some(palette) = &self.palette {
+			let mut palette = palette.clone();
+			for (name, tonal_palette) in PALETTES {
+				for tone in PALETTE_TONES {
+					let color = css_from_argb(tonal_palette(&mut palette).tone(tone));
+					let _ = writeln!(
+						css,
+						"  --md-ref-palette-{}{tone}: {color};",
+						kebab_case(name)
+					);
+				}
+			}
+		}
+		write_roles(&mut css, &self.light, "  ");
+		css.push_str("}\n");
+
+		css.push_str("\n@media (prefers-color-scheme: dark) {\n  :root {\n");
+		write_roles(&mut css, &self.dark, "    ");
+		css.push_str("  }\n}\n");
+
+		if self.data_theme {
+			for (theme, scheme) in [("light", &self.light), ("dark", &self.dark)] {
+				let _ = writeln!(css, "\n[data-theme=\"{theme}\"] {{");
+				write_roles(&mut css, scheme, "  ");
+				css.push_str("}\n");
+			}
+		}
+
+		css
+	}
+}
+
+fn write_roles(css: &mut String, scheme: &Scheme, indent: &str) {
+	for (role, argb) in scheme.roles() {
+		let color = css_from_argb(argb);
+		let _ = writeln!(css, "{indent}--md-sys-color-{}: {color};", kebab_case(role));
+	}
+}
@@ -0,0 +1,180 @@
+use super::{kebab_case, ImportError, PALETTES, PALETTE_TONES};
+use crate::{
+	palettes::CorePalette,
+	scheme::Scheme,
+	utils::{
+		color::Argb,
+		string::{argb_from_css, css_from_argb},
+	},
+};
+use serde::ser::{Serialize, SerializeMap, Serializer};
+use serde_json::Value;
+
+//https://tr.designtokens.org/format/
+
+/// How many references [`scheme_from_tokens`] follows before giving up.
+const MAX_REFERENCE_DEPTH: usize = 16;
+
+/// Writes a light and dark [`Scheme`] and the [`CorePalette`] they come from
+/// as a Design Tokens Community Group document.
+///
+/// Palette tones are `md.ref.palette.{palette}.{tone}`, and roles are
+/// `md.sys.color.{light|dark}.{role}`, referencing the palette tone of the
+/// same color when there is one.
+#[derive(Clone, Debug)]
+pub struct DtcgExporter {
+	light: Scheme,
+	dark: Scheme,
+	palette: CorePalette,
+}
+
+impl DtcgExporter {
+	pub fn new(light: &Scheme, dark: &Scheme, palette: &CorePalette) -> Self {
+		Self {
+			light: light.clone(),
+			dark: dark.clone(),
+			palette: palette.clone(),
+		}
+	}
+
+	pub fn export(&self) -> String {
+		let mut document = serde_json::to_string_pretty(&self.document()).unwrap_or_default();
+		document.push('\n');
+		document
+	}
+
+	fn document(&self) -> Node {
+		let mut palette = self.palette.clone();
+		let mut tones = Vec::new();
+		let mut ref_palettes = Vec::new();
+		for (name, tonal_palette) in PALETTES {
+			let mut ref_tones = Vec::new();
+			for tone in PALETTE_TONES {
+				let argb = tonal_palette(&mut palette).tone(tone);
+				tones.push((name, tone, argb));
+				ref_tones.push((tone.to_string(), Node::Token(css_from_argb(argb))));
+			}
+			ref_palettes.push((kebab_case(name), Node::Group(ref_tones)));
+		}
+
+		let mut sys_colors = Vec::new();
+		for (scheme, variant) in [(&self.light, "light"), (&self.dark, "dark")] {
+			let mut roles = Vec::new();
+			for (role, argb) in scheme.roles() {
+				let value = match find_tone(&tones, role, argb) {
+					Some((name, tone)) => format!("{{md.ref.palette.{}.{tone}}}", kebab_case(name)),
+					None => css_from_argb(argb),
+				};
+				roles.push((kebab_case(role), Node::Token(value)));
+			}
+			sys_colors.push((variant.to_string(), Node::Group(roles)));
+		}
+
+		group([(
+			"md",
+			group([
+				("ref", group([("palette", Node::Group(ref_palettes))])),
+				("sys", group([("color", Node::Group(sys_colors))])),
+			]),
+		)])
+	}
+}
+
+/// A token or a group of them, written with keys in the order they were
+/// added.
+enum Node {
+	Token(String),
+	Group(Vec<(String, Node)>),
+}
+
+impl Serialize for Node {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		match self {
+			Node::Token(value) => {
+				let mut map = serializer.serialize_map(Some(2))?;
+				map.serialize_entry("$type", "color")?;
+				map.serialize_entry("$value", value)?;
+				map.end()
+			}
+			Node::Group(children) => {
+				let mut map = serializer.serialize_map(Some(children.len()))?;
+				for (key, child) in children {
+					map.serialize_entry(key, child)?;
+				}
+				map.end()
+			}
+		}
+	}
+}
+
+fn group<const N: usize>(children: [(&str, Node); N]) -> Node {
+	Node::Group(
+		children
+			.into_iter()
+			.map(|(key, child)| (key.to_string(), child))
+			.collect(),
+	)
+}
+
+/// Reads the light or dark roles of a document written by [`DtcgExporter`],
+/// following references and accepting any CSS color as a value.
+pub fn scheme_from_tokens(json: &str, is_dark: bool) -> Result<Scheme, ImportError> {
+	let document: Value = serde_json::from_str(json)?;
+	let variant = if is_dark { "dark" } else { "light" };
+	Scheme::from_roles(|role| {
+		resolve(
+			&document,
+			&format!("md.sys.color.{variant}.{}", kebab_case(role)),
+			0,
+		)
+	})
+}
+
+/// Palette and tone of `argb`, looking in the palette `role` is usually
+/// drawn from first.
+fn find_tone(
+	tones: &[(&'static str, u8, Argb)],
+	role: &str,
+	argb: Argb,
+) -> Option<(&'static str, u8)> {
+	let palette = if role.contains("primary") {
+		"primary"
+	} else if role.contains("secondary") {
+		"secondary"
+	} else if role.contains("tertiary") {
+		"tertiary"
+	} else if role.contains("error") {
+		"error"
+	} else if role.contains("variant") || role.contains("outline") {
+		"neutral_variant"
+	} else {
+		"neutral"
+	};
+	let preferred = tones.iter().filter(|(name, _, _)| *name == palette);
+	preferred
+		.chain(tones.iter())
+		.find(|(_, _, tone_argb)| *tone_argb == argb)
+		.map(|(name, tone, _)| (*name, *tone))
+}
+
+fn resolve(document: &Value, path: &str, depth: usize) -> Result<Argb, ImportError> {
+	let token = path
+		.split('.')
+		.try_fold(document, |value, key| value.get(key))
+		.ok_or_else(|| ImportError::Missing(path.to_string()))?;
+	let value = match token.get("$value") {
+		Some(Value::Object(color)) => color.get("hex"),
+		value => value,
+	}
+	.and_then(Value::as_str)
+	.ok_or_else(|| ImportError::InvalidColor(path.to_string()))?;
+
+	match value
+		.strip_prefix('{')
+		.and_then(|value| value.strip_suffix('}'))
+	{
+		Some(reference) if depth < MAX_REFERENCE_DEPTH => resolve(document, reference, depth + 1),
+		Some(_) => Err(ImportError::InvalidColor(path.to_string())),
+		None => argb_from_css(value).map_err(|_| ImportError::InvalidColor(path.to_string())),
+	}
+}
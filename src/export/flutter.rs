@@ -0,0 +1,66 @@
+use super::{camel_case, PALETTES, PALETTE_TONES};
+use crate::{palettes::CorePalette, scheme::Scheme};
+use std::fmt::Write;
+
+/// Generates a Dart file with `lightColorScheme` and `darkColorScheme`
+/// constants from a light and dark [`Scheme`].
+#[derive(Clone, Debug)]
+pub struct FlutterExporter {
+	light: Scheme,
+	dark: Scheme,
+	palette: Option<CorePalette>,
+}
+
+impl FlutterExporter {
+	pub fn new(light: &Scheme, dark: &Scheme) -> Self {
+		Self {
+			light: light.clone(),
+			dark: dark.clone(),
+			palette: None,
+		}
+	}
+
+	/// Also writes a `{palette}Palette` constant per palette, mapping tones to
+	/// colors.
+	pub fn with_palette(mut self, palette: &CorePalette) -> Self {
+		self.palette = Some(palette.clone());
+		self
+	}
+
+	pub fn export(&self) -> String {
+		let mut dart = String::from("import 'package:flutter/material.dart';\n");
+
+		for (scheme, brightness) in [(&self.light, "light"), (&self.dark, "dark")] {
+			let _ = writeln!(
+				dart,
+				"\nconst {brightness}ColorScheme = ColorScheme(\n  brightness: Brightness.{brightness},"
+			);
+			for (role, argb) in scheme.roles() {
+				let _ = writeln!(dart, "  {}: Color(0x{argb:08X}),", parameter(role));
+			}
+			dart.push_str(");\n");
+		}
+
+		if let Some(palette) = &self.palette {
+			let mut palette = palette.clone();
+			for (name, tonal_palette) in PALETTES {
+				let _ = writeln!(dart, "\nconst {}Palette = <int, Color>{{", camel_case(name));
+				for tone in PALETTE_TONES {
+					let argb = tonal_palette(&mut palette).tone(tone);
+					let _ = writeln!(dart, "  {tone}: Color(0x{argb:08X}),");
+				}
+				dart.push_str("};\n");
+			}
+		}
+
+		dart
+	}
+}
+
+/// `ColorScheme` parameter of a role.
+fn parameter(role: &str) -> String {
+	match role {
+		"inverse_on_surface" => "onInverseSurface".to_string(),
+		_ => camel_case(role),
+	}
+}
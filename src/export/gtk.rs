@@ -0,0 +1,73 @@
+use crate::{scheme::Scheme, utils::color::Argb};
+use std::fmt::Write;
+
+/// Writes a [`Scheme`] as libadwaita named colors, for an app's `style.css`
+/// or `style-dark.css`.
+#[derive(Clone, Debug)]
+pub struct GtkExporter {
+	scheme: Scheme,
+}
+
+impl GtkExporter {
+	pub fn new(scheme: &Scheme) -> Self {
+		Self {
+			scheme: scheme.clone(),
+		}
+	}
+
+	/// libadwaita color names and their colors.
+	pub fn colors(&self) -> Vec<(&'static str, Argb)> {
+		let s = &self.scheme;
+		vec![
+			("accent_color", s.primary()),
+			("accent_bg_color", s.primary()),
+			("accent_fg_color", s.on_primary()),
+			("destructive_color", s.error()),
+			("destructive_bg_color", s.error()),
+			("destructive_fg_color", s.on_error()),
+			("error_color", s.error()),
+			("error_bg_color", s.error()),
+			("error_fg_color", s.on_error()),
+			("window_bg_color", s.background()),
+			("window_fg_color", s.on_background()),
+			("view_bg_color", s.surface()),
+			("view_fg_color", s.on_surface()),
+			("headerbar_bg_color", s.surface_variant()),
+			("headerbar_fg_color", s.on_surface_variant()),
+			("headerbar_border_color", s.outline_variant()),
+			("headerbar_backdrop_color", s.background()),
+			("sidebar_bg_color", s.surface_variant()),
+			("sidebar_fg_color", s.on_surface_variant()),
+			("card_bg_color", s.surface_variant()),
+			("card_fg_color", s.on_surface_variant()),
+			("dialog_bg_color", s.surface()),
+			("dialog_fg_color", s.on_surface()),
+			("popover_bg_color", s.surface()),
+			("popover_fg_color", s.on_surface()),
+		]
+	}
+
+	pub fn export(&self) -> String {
+		let mut css = String::new();
+		for (name, argb) in self.colors() {
+			let _ = writeln!(css, "@define-color {name} {};", gtk_color(argb));
+		}
+		css
+	}
+}
+
+/// `#rrggbb`, or `rgba()` when translucent, as GTK reads eight hex digits
+/// with alpha last.
+fn gtk_color(argb: Argb) -> String {
+	if argb.is_opaque() {
+		argb.to_string()
+	} else {
+		format!(
+			"rgba({}, {}, {}, {})",
+			argb.red(),
+			argb.green(),
+			argb.blue(),
+			(argb.alpha() as f64 / 255.0 * 1000.0).round() / 1000.0
+		)
+	}
+}
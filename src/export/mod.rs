@@ -0,0 +1,154 @@
+use crate::{
+	dynamic_color::{DynamicScheme, MaterialDynamicColors},
+	palettes::{CorePalette, TonalPalette},
+	utils::{
+		color::Argb,
+		contrast::{darker, lighter, ratio_of_tones},
+	},
+};
+
+pub mod android;
+pub mod compose;
+pub mod css;
+#[cfg(feature = "json")]
+pub mod dtcg;
+pub mod flutter;
+pub mod gtk;
+pub mod preview;
+pub mod qt;
+pub mod tailwind;
+pub mod terminal;
+#[cfg(feature = "json")]
+pub mod theme_builder;
+pub mod vscode;
+pub mod xcassets;
+
+pub use android::AndroidExporter;
+pub use compose::ComposeExporter;
+pub use css::CssExporter;
+#[cfg(feature = "json")]
+pub use dtcg::DtcgExporter;
+pub use flutter::FlutterExporter;
+pub use gtk::GtkExporter;
+pub use preview::{PalettePreview, Preview};
+pub use qt::QtExporter;
+pub use tailwind::TailwindExporter;
+pub use terminal::TerminalTheme;
+#[cfg(feature = "json")]
+pub use theme_builder::{ExtendedColor, MaterialTheme};
+pub use vscode::VsCodeTheme;
+pub use xcassets::XcassetsExporter;
+
+/// Tones written out for each tonal palette.
+pub(crate) const PALETTE_TONES: [u8; 13] = [0, 10, 20, 30, 40, 50, 60, 70, 80, 90, 95, 99, 100];
+
+/// Why a theme document could not be read back.
+#[cfg(feature = "json")]
+#[derive(Debug)]
+pub enum ImportError {
+	Json(serde_json::Error),
+	/// No value at this path.
+	Missing(String),
+	/// A value at this path that is not a color, or a reference that does not
+	/// resolve to one.
+	InvalidColor(String),
+}
+
+#[cfg(feature = "json")]
+impl std::fmt::Display for ImportError {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			ImportError::Json(error) => write!(f, "invalid json: {error}"),
+			ImportError::Missing(path) => write!(f, "missing {path}"),
+			ImportError::InvalidColor(path) => write!(f, "invalid color at {path}"),
+		}
+	}
+}
+
+#[cfg(feature = "json")]
+impl std::error::Error for ImportError {
+	fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+		match self {
+			ImportError::Json(error) => Some(error),
+			_ => None,
+		}
+	}
+}
+
+#[cfg(feature = "json")]
+impl From<serde_json::Error> for ImportError {
+	fn from(error: serde_json::Error) -> Self {
+		ImportError::Json(error)
+	}
+}
+
+type PaletteGetter = fn(&mut CorePalette) -> &mut TonalPalette;
+
+/// The palettes of a [`CorePalette`] under their Material names.
+pub(crate) const PALETTES: [(&str, PaletteGetter); 6] = [
+	("primary", CorePalette::a1),
+	("secondary", CorePalette::a2),
+	("tertiary", CorePalette::a3),
+	("neutral", CorePalette::n1),
+	("neutral_variant", CorePalette::n2),
+	("error", CorePalette::error),
+];
+
+/// `tone`, or the tone nearest to it that reaches `ratio` against
+/// `background`, on the same side of it when possible.
+pub(crate) fn contrasting_tone(tone: f64, background: f64, ratio: f64) -> f64 {
+	if ratio_of_tones(tone, background) >= ratio {
+		return tone;
+	}
+
+	let lighter = || lighter(background, ratio).map(|tone| tone.ceil().min(100.0));
+	let darker = || darker(background, ratio).map(|tone| tone.floor().max(0.0));
+	let contrasting = if tone >= background {
+		lighter().or_else(darker)
+	} else {
+		darker().or_else(lighter)
+	};
+	contrasting.unwrap_or(
+		if ratio_of_tones(100.0, background) >= ratio_of_tones(0.0, background) {
+			100.0
+		} else {
+			0.0
+		},
+	)
+}
+
+pub(crate) fn kebab_case(snake_case: &str) -> String {
+	snake_case.replace('_', "-")
+}
+
+/// Escapes a name for both TOML and JSON strings.
+pub(crate) fn escape(name: &str) -> String {
+	name.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// Surface roles that [`Scheme`](crate::scheme::Scheme) does not carry.
+pub(crate) fn surface_container_roles(scheme: &mut DynamicScheme) -> [(&'static str, Argb); 7] {
+	[
+		MaterialDynamicColors::surface_dim(),
+		MaterialDynamicColors::surface_bright(),
+		MaterialDynamicColors::surface_container_lowest(),
+		MaterialDynamicColors::surface_container_low(),
+		MaterialDynamicColors::surface_container(),
+		MaterialDynamicColors::surface_container_high(),
+		MaterialDynamicColors::surface_container_highest(),
+	]
+	.map(|color| (color.name(), color.get_argb(scheme)))
+}
+
+pub(crate) fn camel_case(snake_case: &str) -> String {
+	let mut words = snake_case.split('_');
+	let mut camel_case = words.next().unwrap_or_default().to_string();
+	for word in words {
+		let mut chars = word.chars();
+		if let Some(first) = chars.next() {
+			camel_case.extend(first.to_uppercase());
+			camel_case.push_str(chars.as_str());
+		}
+	}
+	camel_case
+}
@@ -0,0 +1,356 @@
+use super::{kebab_case, PALETTES, PALETTE_TONES};
+use crate::{
+	palettes::{CorePalette, TonalPalette},
+	scheme::Scheme,
+	utils::{
+		color::{lstar_from_argb, Argb},
+		contrast::ratio_of_tones,
+		string::css_from_argb,
+	},
+};
+use std::fmt::Write;
+
+const MARGIN: u32 = 16;
+const GAP: u32 = 8;
+const COLUMNS: u32 = 4;
+const SWATCH_HEIGHT: u32 = 88;
+const LABEL_WIDTH: u32 = 128;
+const TONE_WIDTH: u32 = 64;
+const TONE_HEIGHT: u32 = 48;
+const TITLE_HEIGHT: u32 = 40;
+const HEADING_HEIGHT: u32 = 32;
+
+/// Resets terminal colors after a line.
+const ANSI_RESET: &str = "\x1b[0m";
+
+/// Renders a [`Scheme`] and the [`CorePalette`] it comes from as a standalone
+/// SVG image or HTML page, for design reviews, or as ANSI escape sequences
+/// to print in a terminal.
+///
+/// Each role that has an on-color is shown as a swatch labeled with its
+/// name, hex value and the contrast ratio of the pair, followed by a strip
+/// of tones for each palette.
+#[derive(Clone, Debug)]
+pub struct Preview {
+	scheme: Scheme,
+	palette: CorePalette,
+	title: String,
+}
+
+/// Renders the tones of a single [`TonalPalette`] as a strip of ANSI escape
+/// sequences to print in a terminal.
+#[derive(Clone, Debug)]
+pub struct PalettePreview {
+	palette: TonalPalette,
+}
+
+/// A role, its on-color and their contrast ratio.
+struct Pair {
+	role: &'static str,
+	on_role: &'static str,
+	background: Argb,
+	foreground: Argb,
+	ratio: f64,
+}
+
+/// A palette name and its tones with their colors.
+type Strip = (String, Vec<(u8, Argb)>);
+
+impl Preview {
+	pub fn new(scheme: &Scheme, palette: &CorePalette) -> Self {
+		Self {
+			scheme: scheme.clone(),
+			palette: palette.clone(),
+			title: "Material color scheme".to_string(),
+		}
+	}
+
+	/// Heading of the preview, `Material color scheme` by default.
+	pub fn with_title(mut self, title: &str) -> Self {
+		self.title = title.to_string();
+		self
+	}
+
+	/// Roles with their on-color, such as `primary` and `on_primary`, and the
+	/// contrast ratio between them.
+	pub fn contrast_ratios(&self) -> Vec<(&'static str, &'static str, f64)> {
+		self.pairs()
+			.into_iter()
+			.map(|pair| (pair.role, pair.on_role, pair.ratio))
+			.collect()
+	}
+
+	pub fn svg(&self) -> String {
+		let pairs = self.pairs();
+		let strips = self.strips();
+		let width = 2 * MARGIN + LABEL_WIDTH + PALETTE_TONES.len() as u32 * TONE_WIDTH;
+		let swatch_width = (width - 2 * MARGIN - (COLUMNS - 1) * GAP) / COLUMNS;
+		let rows = (pairs.len() as u32).div_ceil(COLUMNS);
+		let palettes_top = MARGIN + TITLE_HEIGHT + rows * (SWATCH_HEIGHT + GAP) + HEADING_HEIGHT;
+		let height = palettes_top + strips.len() as u32 * TONE_HEIGHT + MARGIN;
+
+		let mut svg = format!(
+			"<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{height}\" \
+			 viewBox=\"0 0 {width} {height}\" font-family=\"sans-serif\">\n  \
+			 <rect width=\"100%\" height=\"100%\" fill=\"{}\"/>\n  \
+			 <text x=\"{MARGIN}\" y=\"{}\" font-size=\"20\" fill=\"{}\">{}</text>\n",
+			css_from_argb(self.scheme.background()),
+			MARGIN + 20,
+			css_from_argb(self.scheme.on_background()),
+			escape(&self.title)
+		);
+
+		for (i, pair) in pairs.iter().enumerate() {
+			let x = MARGIN + (i as u32 % COLUMNS) * (swatch_width + GAP);
+			let y = MARGIN + TITLE_HEIGHT + (i as u32 / COLUMNS) * (SWATCH_HEIGHT + GAP);
+			let _ = write!(
+				svg,
+				"  <g fill=\"{}\">\n    \
+				 <rect x=\"{x}\" y=\"{y}\" width=\"{swatch_width}\" height=\"{SWATCH_HEIGHT}\" rx=\"8\" fill=\"{}\"/>\n    \
+				 <text x=\"{}\" y=\"{}\" font-size=\"14\">{}</text>\n    \
+				 <text x=\"{}\" y=\"{}\" font-size=\"12\">{}</text>\n    \
+				 <text x=\"{}\" y=\"{}\" font-size=\"12\">{} {:.1}:1</text>\n  </g>\n",
+				css_from_argb(pair.foreground),
+				css_from_argb(pair.background),
+				x + 12,
+				y + 24,
+				pair.role,
+				x + 12,
+				y + 48,
+				css_from_argb(pair.background),
+				x + 12,
+				y + 72,
+				pair.on_role,
+				pair.ratio
+			);
+		}
+
+		let _ = writeln!(
+			svg,
+			"  <text x=\"{MARGIN}\" y=\"{}\" font-size=\"16\" fill=\"{}\">Palettes</text>",
+			palettes_top - 12,
+			css_from_argb(self.scheme.on_background())
+		);
+		for (row, (name, tones)) in strips.iter().enumerate() {
+			let y = palettes_top + row as u32 * TONE_HEIGHT;
+			let _ = writeln!(
+				svg,
+				"  <text x=\"{MARGIN}\" y=\"{}\" font-size=\"12\" fill=\"{}\">{name}</text>",
+				y + 28,
+				css_from_argb(self.scheme.on_background())
+			);
+			for (column, (tone, color)) in tones.iter().enumerate() {
+				let x = MARGIN + LABEL_WIDTH + column as u32 * TONE_WIDTH;
+				let color = css_from_argb(*color);
+				let _ = write!(
+					svg,
+					"  <g fill=\"{}\" font-size=\"10\">\n    \
+					 <rect x=\"{x}\" y=\"{y}\" width=\"{TONE_WIDTH}\" height=\"{TONE_HEIGHT}\" fill=\"{color}\"/>\n    \
+					 <text x=\"{}\" y=\"{}\">{tone}</text>\n    \
+					 <text x=\"{}\" y=\"{}\">{color}</text>\n  </g>\n",
+					label_color(*tone),
+					x + 6,
+					y + 16,
+					x + 6,
+					y + 38
+				);
+			}
+		}
+
+		svg.push_str("</svg>\n");
+		svg
+	}
+
+	pub fn html(&self) -> String {
+		let mut html = format!(
+			"<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n<title>{title}</title>\n\
+			 <style>\n\
+			 body {{ margin: {MARGIN}px; font-family: sans-serif; background: {}; color: {}; }}\n\
+			 .swatches {{ display: grid; grid-template-columns: repeat({COLUMNS}, 1fr); gap: {GAP}px; }}\n\
+			 .swatch {{ padding: 12px; border-radius: 8px; }}\n\
+			 .swatch span {{ display: block; font-size: 12px; margin-top: 8px; }}\n\
+			 table {{ border-collapse: collapse; }}\n\
+			 th {{ text-align: left; font-weight: normal; padding-right: 16px; }}\n\
+			 td {{ width: {TONE_WIDTH}px; height: {TONE_HEIGHT}px; font-size: 10px; vertical-align: top; }}\n\
+			 </style>\n</head>\n<body>\n<h1>{title}</h1>\n<div class=\"swatches\">\n",
+			css_from_argb(self.scheme.background()),
+			css_from_argb(self.scheme.on_background()),
+			title = escape(&self.title)
+		);
+
+		for pair in self.pairs() {
+			let _ = writeln!(
+				html,
+				"<div class=\"swatch\" style=\"background: {}; color: {};\">{}\
+				 <span>{}</span><span>{} {:.1}:1</span></div>",
+				css_from_argb(pair.background),
+				css_from_argb(pair.foreground),
+				pair.role,
+				css_from_argb(pair.background),
+				pair.on_role,
+				pair.ratio
+			);
+		}
+
+		html.push_str("</div>\n<h2>Palettes</h2>\n<table>\n");
+		for (name, tones) in self.strips() {
+			let _ = write!(html, "<tr><th>{name}</th>");
+			for (tone, color) in tones {
+				let color = css_from_argb(color);
+				let _ = write!(
+					html,
+					"<td style=\"background: {color}; color: {};\">{tone}<br>{color}</td>",
+					label_color(tone)
+				);
+			}
+			html.push_str("</tr>\n");
+		}
+		html.push_str("</table>\n</body>\n</html>\n");
+		html
+	}
+
+	/// The preview as lines of 24-bit ANSI escape sequences to print. Roles
+	/// with an on-color are written in it, along with the contrast ratio of
+	/// the pair, followed by the remaining roles and the palette strips.
+	pub fn ansi(&self) -> String {
+		let pairs = self.pairs();
+		let mut ansi = String::new();
+		for pair in &pairs {
+			let _ = writeln!(
+				ansi,
+				"{} {:<24} {}  {} {:.1}:1 {ANSI_RESET}",
+				ansi_colors(pair.background, Some(pair.foreground)),
+				pair.role,
+				pair.background,
+				pair.on_role,
+				pair.ratio
+			);
+		}
+		for (role, background) in self.scheme.roles() {
+			if pairs
+				.iter()
+				.any(|pair| pair.role == role || pair.on_role == role)
+			{
+				continue;
+			}
+			let _ = writeln!(
+				ansi,
+				"{} {role:<24} {background} {ANSI_RESET}",
+				ansi_colors(background, None)
+			);
+		}
+		for (name, tones) in self.strips() {
+			let _ = write!(ansi, "{name:<16}");
+			ansi.push_str(&ansi_strip(&tones));
+		}
+		ansi
+	}
+
+	fn pairs(&self) -> Vec<Pair> {
+		let roles = self.scheme.roles();
+
+		roles
+			.iter()
+			.filter_map(|(role, background)| {
+				let (on_role, foreground) = on_color(&roles, role)?;
+				Some(Pair {
+					role,
+					on_role,
+					background: *background,
+					foreground,
+					ratio: ratio_of_tones(
+						lstar_from_argb(*background),
+						lstar_from_argb(foreground),
+					),
+				})
+			})
+			.collect()
+	}
+
+	fn strips(&self) -> Vec<Strip> {
+		let mut palette = self.palette.clone();
+		PALETTES
+			.iter()
+			.map(|(name, tonal_palette)| {
+				let tones = PALETTE_TONES
+					.iter()
+					.map(|tone| (*tone, tonal_palette(&mut palette).tone(*tone)))
+					.collect();
+				(kebab_case(name), tones)
+			})
+			.collect()
+	}
+}
+
+impl PalettePreview {
+	pub fn new(palette: &TonalPalette) -> Self {
+		Self {
+			palette: palette.clone(),
+		}
+	}
+
+	/// The tones of the palette as a strip of 24-bit ANSI escape sequences to
+	/// print.
+	pub fn ansi(&self) -> String {
+		let mut palette = self.palette.clone();
+		let tones: Vec<_> = PALETTE_TONES
+			.iter()
+			.map(|tone| (*tone, palette.tone(*tone)))
+			.collect();
+		ansi_strip(&tones)
+	}
+}
+
+/// On-color of `role`, such as `on_primary` for `primary`.
+fn on_color(roles: &[(&'static str, Argb)], role: &str) -> Option<(&'static str, Argb)> {
+	roles
+		.iter()
+		.find(|(on_role, _)| match role {
+			"inverse_surface" => *on_role == "inverse_on_surface",
+			role => on_role.strip_prefix("on_") == Some(role),
+		})
+		.copied()
+}
+
+/// Escape sequences setting `background` and `foreground`, or black or white
+/// text if there is no foreground.
+fn ansi_colors(background: Argb, foreground: Option<Argb>) -> String {
+	let foreground =
+		foreground.unwrap_or_else(|| label_color(lstar_from_argb(background).round() as u8));
+	format!(
+		"\x1b[48;2;{};{};{}m\x1b[38;2;{};{};{}m",
+		background.red(),
+		background.green(),
+		background.blue(),
+		foreground.red(),
+		foreground.green(),
+		foreground.blue()
+	)
+}
+
+/// A line of `tones` on their own background, each labeled with its tone.
+fn ansi_strip(tones: &[(u8, Argb)]) -> String {
+	let mut ansi = String::new();
+	for (tone, color) in tones {
+		let _ = write!(ansi, "{} {tone:>3} ", ansi_colors(*color, None));
+	}
+	let _ = writeln!(ansi, "{ANSI_RESET}");
+	ansi
+}
+
+/// Label color readable on a tone.
+fn label_color(tone: u8) -> Argb {
+	if tone < 50 {
+		Argb::from_rgb(0xff, 0xff, 0xff)
+	} else {
+		Argb::from_rgb(0x00, 0x00, 0x00)
+	}
+}
+
+/// Escapes text for both SVG and HTML.
+fn escape(text: &str) -> String {
+	text.replace('&', "&amp;")
+		.replace('<', "&lt;")
+		.replace('>', "&gt;")
+		.replace('"', "&quot;")
+}
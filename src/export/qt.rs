@@ -0,0 +1,85 @@
+use crate::{scheme::Scheme, utils::color::Argb};
+use std::fmt::Write;
+
+/// Writes a [`Scheme`] as a Qt palette and a matching style sheet.
+#[derive(Clone, Debug)]
+pub struct QtExporter {
+	scheme: Scheme,
+}
+
+impl QtExporter {
+	pub fn new(scheme: &Scheme) -> Self {
+		Self {
+			scheme: scheme.clone(),
+		}
+	}
+
+	/// `QPalette::ColorRole` names and their colors, leaving out `Accent` as it
+	/// needs Qt 6.6.
+	pub fn palette(&self) -> Vec<(&'static str, Argb)> {
+		let s = &self.scheme;
+		vec![
+			("Window", s.background()),
+			("WindowText", s.on_background()),
+			("Base", s.surface()),
+			("AlternateBase", s.surface_variant()),
+			("ToolTipBase", s.inverse_surface()),
+			("ToolTipText", s.inverse_on_surface()),
+			("PlaceholderText", s.outline()),
+			("Text", s.on_surface()),
+			("Button", s.secondary_container()),
+			("ButtonText", s.on_secondary_container()),
+			("BrightText", s.error()),
+			("Highlight", s.primary()),
+			("HighlightedText", s.on_primary()),
+			("Link", s.primary()),
+			("LinkVisited", s.tertiary()),
+		]
+	}
+
+	/// C++ that builds the palette, for `QApplication::setPalette`.
+	pub fn palette_cpp(&self) -> String {
+		let mut cpp = String::from("QPalette palette;\n");
+		for (role, argb) in self.palette() {
+			let _ = writeln!(
+				cpp,
+				"palette.setColor(QPalette::{role}, QColor::fromRgba(0x{argb:08x}));"
+			);
+		}
+		cpp
+	}
+
+	/// A Qt style sheet for common widgets. Colors are `#rrggbb`, or
+	/// `#aarrggbb` when translucent, which Qt reads with alpha first.
+	pub fn qss(&self) -> String {
+		let s = &self.scheme;
+		format!(
+			"QWidget {{\n    background-color: {};\n    color: {};\n    \
+			 selection-background-color: {};\n    selection-color: {};\n}}\n\n\
+			 QPushButton {{\n    background-color: {};\n    color: {};\n    border: none;\n    \
+			 border-radius: 20px;\n    padding: 8px 24px;\n}}\n\n\
+			 QLineEdit, QTextEdit, QPlainTextEdit {{\n    background-color: {};\n    color: {};\n    \
+			 border: 1px solid {};\n    border-radius: 4px;\n}}\n\n\
+			 QLineEdit:focus, QTextEdit:focus, QPlainTextEdit:focus {{\n    border: 2px solid {};\n}}\n\n\
+			 QMenu {{\n    background-color: {};\n    color: {};\n}}\n\n\
+			 QMenu::item:selected {{\n    background-color: {};\n    color: {};\n}}\n\n\
+			 QToolTip {{\n    background-color: {};\n    color: {};\n    border: none;\n}}\n",
+			s.background(),
+			s.on_background(),
+			s.primary_container(),
+			s.on_primary_container(),
+			s.primary(),
+			s.on_primary(),
+			s.surface_variant(),
+			s.on_surface_variant(),
+			s.outline(),
+			s.primary(),
+			s.surface_variant(),
+			s.on_surface_variant(),
+			s.secondary_container(),
+			s.on_secondary_container(),
+			s.inverse_surface(),
+			s.inverse_on_surface()
+		)
+	}
+}
@@ -0,0 +1,93 @@
+use super::{kebab_case, PALETTES, PALETTE_TONES};
+use crate::{palettes::CorePalette, scheme::Scheme, utils::string::css_from_argb};
+use std::fmt::Write;
+
+/// Generates Tailwind colors from a [`CorePalette`], as `{palette}-{tone}`
+/// scales such as `primary-40` or `neutral-variant-90`.
+///
+/// Scheme roles can be added as aliases, either with a scheme's colors or
+/// pointing at the `--md-sys-color-{role}` properties written by
+/// [`CssExporter`](super::CssExporter), so they follow its dark mode.
+#[derive(Clone, Debug)]
+pub struct TailwindExporter {
+	palette: CorePalette,
+	aliases: Option<Aliases>,
+}
+
+#[derive(Clone, Debug)]
+enum Aliases {
+	Scheme(Scheme),
+	CssVariables,
+}
+
+impl TailwindExporter {
+	pub fn new(palette: &CorePalette) -> Self {
+		Self {
+			palette: palette.clone(),
+			aliases: None,
+		}
+	}
+
+	/// Adds role aliases such as `on-primary` with the colors of `scheme`.
+	pub fn with_scheme(mut self, scheme: &Scheme) -> Self {
+		self.aliases = Some(Aliases::Scheme(scheme.clone()));
+		self
+	}
+
+	/// Adds role aliases such as `on-primary` as `var(--md-sys-color-on-primary)`.
+	pub fn with_css_variables(mut self) -> Self {
+		self.aliases = Some(Aliases::CssVariables);
+		self
+	}
+
+	/// A Tailwind v3 `tailwind.config.js` extending the theme colors.
+	pub fn config(&self) -> String {
+		let mut js = String::from(
+			"/** @type {import('tailwindcss').Config} */\nmodule.exports = {\n  theme: {\n    extend: {\n      colors: {\n",
+		);
+		for (name, value) in self.colors() {
+			let _ = writeln!(js, "        '{name}': '{value}',");
+		}
+		js.push_str("      },\n    },\n  },\n};\n");
+		js
+	}
+
+	/// A Tailwind v4 `@theme` block.
+	pub fn theme_css(&self) -> String {
+		let mut css = String::from("@theme {\n");
+		for (name, value) in self.colors() {
+			let _ = writeln!(css, "  --color-{name}: {value};");
+		}
+		css.push_str("}\n");
+		css
+	}
+
+	fn colors(&self) -> Vec<(String, String)> {
+		let mut palette = self.palette.clone();
+		let mut colors = Vec::new();
+		for (name, tonal_palette) in PALETTES {
+			for tone in PALETTE_TONES {
+				colors.push((
+					format!("{}-{tone}", kebab_case(name)),
+					css_from_argb(tonal_palette(&mut palette).tone(tone)),
+				));
+			}
+		}
+
+		match &self.aliases {
+			Some(Aliases::Scheme(scheme)) => {
+				for (role, argb) in scheme.roles() {
+					colors.push((kebab_case(role), css_from_argb(argb)));
+				}
+			}
+			Some(Aliases::CssVariables) => {
+				for role in Scheme::ROLE_NAMES {
+					let role = kebab_case(role);
+					colors.push((role.clone(), format!("var(--md-sys-color-{role})")));
+				}
+			}
+			None => {}
+		}
+		colors
+	}
+}
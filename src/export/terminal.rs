@@ -0,0 +1,210 @@
+use super::{contrasting_tone, escape};
+use crate::{
+	blend::harmonize,
+	hct::Hct,
+	palettes::{CorePalette, TonalPalette},
+	scheme::Scheme,
+	utils::color::{lstar_from_argb, Argb},
+};
+use std::fmt::Write;
+
+/// ANSI colors 1 to 6, red to cyan, before harmonizing toward the source
+/// color.
+const ANSI_BASES: [u32; 6] = [
+	0xffff0000, 0xff00ff00, 0xffffff00, 0xff0000ff, 0xffff00ff, 0xff00ffff,
+];
+
+const ANSI_NAMES: [&str; 8] = [
+	"black", "red", "green", "yellow", "blue", "magenta", "cyan", "white",
+];
+
+/// Windows Terminal's names for the 16 ANSI colors.
+const WINDOWS_TERMINAL_NAMES: [&str; 16] = [
+	"black",
+	"red",
+	"green",
+	"yellow",
+	"blue",
+	"purple",
+	"cyan",
+	"white",
+	"brightBlack",
+	"brightRed",
+	"brightGreen",
+	"brightYellow",
+	"brightBlue",
+	"brightPurple",
+	"brightCyan",
+	"brightWhite",
+];
+
+/// Chroma of the harmonized ANSI colors, high enough that red stays red.
+const ANSI_CHROMA: f64 = 60.0;
+
+/// Contrast ANSI colors 1 to 6 and 9 to 14 keep against the background.
+const ANSI_CONTRAST: f64 = 3.0;
+
+/// A terminal color theme derived from a [`Scheme`], with the 16 ANSI colors
+/// harmonized toward its source color.
+///
+/// Whether it is dark follows the scheme's surface, and every ANSI color but
+/// black and white moves away from it until it reaches 3:1. Black and white
+/// come from the neutral palette of the source color, like the scheme's
+/// surfaces, at fixed tones so that all four stay apart.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TerminalTheme {
+	name: String,
+	foreground: Argb,
+	background: Argb,
+	cursor: Argb,
+	cursor_text: Argb,
+	selection_foreground: Argb,
+	selection_background: Argb,
+	ansi: [Argb; 16],
+}
+
+impl TerminalTheme {
+	pub fn new(scheme: &Scheme, source: Argb) -> Self {
+		let background_tone = lstar_from_argb(scheme.surface());
+		let is_dark = background_tone < 50.0;
+		let mut neutral = CorePalette::of(source).n1().clone();
+		// Normal and bright tones, in dark and light themes.
+		let (black, white, normal, bright) = if is_dark {
+			((10, 50), (80, 95), 70, 80)
+		} else {
+			((10, 40), (80, 95), 40, 50)
+		};
+		let tone = |palette: &mut TonalPalette, tone: u8| {
+			palette.tone(contrasting_tone(tone as f64, background_tone, ANSI_CONTRAST) as u8)
+		};
+
+		let mut ansi = [Argb::default(); 16];
+		ansi[0] = neutral.tone(black.0);
+		ansi[8] = neutral.tone(black.1);
+		ansi[7] = neutral.tone(white.0);
+		ansi[15] = neutral.tone(white.1);
+		for (i, base) in ANSI_BASES.into_iter().enumerate() {
+			let hue = Hct::from_argb(harmonize(Argb::from(base), source)).hue();
+			let mut palette = TonalPalette::from_hue_and_chroma(hue, ANSI_CHROMA);
+			ansi[i + 1] = tone(&mut palette, normal);
+			ansi[i + 9] = tone(&mut palette, bright);
+		}
+
+		Self {
+			name: "Material".to_string(),
+			foreground: scheme.on_surface(),
+			background: scheme.surface(),
+			cursor: scheme.primary(),
+			cursor_text: scheme.on_primary(),
+			selection_foreground: scheme.on_primary_container(),
+			selection_background: scheme.primary_container(),
+			ansi,
+		}
+	}
+
+	/// Name used by the WezTerm and Windows Terminal exports, `Material` by
+	/// default.
+	pub fn with_name(mut self, name: &str) -> Self {
+		self.name = name.to_string();
+		self
+	}
+
+	pub fn foreground(&self) -> Argb {
+		self.foreground
+	}
+
+	pub fn background(&self) -> Argb {
+		self.background
+	}
+
+	/// ANSI colors 0 to 15: black, red, green, yellow, blue, magenta, cyan and
+	/// white, then their bright variants.
+	pub fn ansi(&self) -> [Argb; 16] {
+		self.ansi
+	}
+
+	/// An Alacritty `[colors]` TOML section.
+	pub fn alacritty(&self) -> String {
+		let mut toml = format!(
+			"[colors.primary]\nbackground = \"{}\"\nforeground = \"{}\"\n\n\
+			 [colors.cursor]\ntext = \"{}\"\ncursor = \"{}\"\n\n\
+			 [colors.selection]\ntext = \"{}\"\nbackground = \"{}\"\n",
+			self.background,
+			self.foreground,
+			self.cursor_text,
+			self.cursor,
+			self.selection_foreground,
+			self.selection_background
+		);
+		for (section, colors) in [("normal", &self.ansi[..8]), ("bright", &self.ansi[8..])] {
+			let _ = writeln!(toml, "\n[colors.{section}]");
+			for (name, argb) in ANSI_NAMES.iter().zip(colors) {
+				let _ = writeln!(toml, "{name} = \"{argb}\"");
+			}
+		}
+		toml
+	}
+
+	/// Kitty `kitty.conf` color settings.
+	pub fn kitty(&self) -> String {
+		let mut conf = format!(
+			"foreground {}\nbackground {}\ncursor {}\ncursor_text_color {}\n\
+			 selection_foreground {}\nselection_background {}\n",
+			self.foreground,
+			self.background,
+			self.cursor,
+			self.cursor_text,
+			self.selection_foreground,
+			self.selection_background
+		);
+		for (i, argb) in self.ansi.iter().enumerate() {
+			let _ = writeln!(conf, "color{i} {argb}");
+		}
+		conf
+	}
+
+	/// A WezTerm color scheme file.
+	pub fn wezterm(&self) -> String {
+		let list = |colors: &[Argb]| {
+			colors
+				.iter()
+				.map(|argb| format!("\"{argb}\""))
+				.collect::<Vec<_>>()
+				.join(", ")
+		};
+		format!(
+			"[colors]\nforeground = \"{}\"\nbackground = \"{}\"\ncursor_bg = \"{}\"\n\
+			 cursor_fg = \"{}\"\ncursor_border = \"{}\"\nselection_fg = \"{}\"\n\
+			 selection_bg = \"{}\"\nansi = [{}]\nbrights = [{}]\n\n\
+			 [metadata]\nname = \"{}\"\n",
+			self.foreground,
+			self.background,
+			self.cursor,
+			self.cursor_text,
+			self.cursor,
+			self.selection_foreground,
+			self.selection_background,
+			list(&self.ansi[..8]),
+			list(&self.ansi[8..]),
+			escape(&self.name)
+		)
+	}
+
+	/// A Windows Terminal `schemes` entry.
+	pub fn windows_terminal(&self) -> String {
+		let mut json = format!(
+			"{{\n    \"name\": \"{}\",\n    \"background\": \"{}\",\n    \"foreground\": \"{}\",\n    \
+			 \"cursorColor\": \"{}\",\n    \"selectionBackground\": \"{}\"",
+			escape(&self.name),
+			self.background,
+			self.foreground,
+			self.cursor,
+			self.selection_background
+		);
+		for (name, argb) in WINDOWS_TERMINAL_NAMES.iter().zip(self.ansi) {
+			let _ = write!(json, ",\n    \"{name}\": \"{argb}\"");
+		}
+		json.push_str("\n}\n");
+		json
+	}
+}
@@ -0,0 +1,272 @@
+use super::{
+	camel_case, kebab_case, surface_container_roles, ImportError, PaletteGetter, PALETTES,
+	PALETTE_TONES,
+};
+use crate::{
+	blend::harmonize, dynamic_color::DynamicScheme, palettes::CorePalette, scheme::Scheme,
+	utils::color::Argb,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Schemes written for each theme, with their contrast level.
+const SCHEMES: [(&str, bool, f64); 6] = [
+	("light", false, 0.0),
+	("light-medium-contrast", false, 0.5),
+	("light-high-contrast", false, 1.0),
+	("dark", true, 0.0),
+	("dark-medium-contrast", true, 0.5),
+	("dark-high-contrast", true, 1.0),
+];
+
+/// A Material Theme Builder `material-theme.json` document.
+///
+/// Schemes and palettes are kept as read, so a file round-trips even with
+/// roles [`Scheme`] does not carry.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MaterialTheme {
+	#[serde(default)]
+	description: String,
+	seed: Argb,
+	core_colors: CoreColors,
+	#[serde(default)]
+	extended_colors: Vec<ExtendedColor>,
+	schemes: BTreeMap<String, BTreeMap<String, Argb>>,
+	#[serde(default)]
+	palettes: BTreeMap<String, BTreeMap<u8, Argb>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CoreColors {
+	primary: Argb,
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	secondary: Option<Argb>,
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	tertiary: Option<Argb>,
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	neutral: Option<Argb>,
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	neutral_variant: Option<Argb>,
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	error: Option<Argb>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ExtendedColor {
+	name: String,
+	color: Argb,
+	#[serde(default)]
+	description: String,
+	#[serde(default)]
+	harmonized: bool,
+}
+
+impl ExtendedColor {
+	pub fn new(name: &str, color: Argb, harmonized: bool) -> Self {
+		Self {
+			name: name.to_string(),
+			color,
+			description: String::new(),
+			harmonized,
+		}
+	}
+
+	pub fn name(&self) -> &str {
+		&self.name
+	}
+
+	pub fn color(&self) -> Argb {
+		self.color
+	}
+
+	pub fn description(&self) -> &str {
+		&self.description
+	}
+
+	pub fn harmonized(&self) -> bool {
+		self.harmonized
+	}
+
+	/// The color, shifted toward `source` when it is harmonized.
+	pub fn value(&self, source: Argb) -> Argb {
+		if self.harmonized {
+			harmonize(self.color, source)
+		} else {
+			self.color
+		}
+	}
+
+	/// Roles of the color in a light or dark scheme, as upstream's
+	/// `customColor`: `{name}`, `on{Name}`, `{name}Container` and
+	/// `on{Name}Container`.
+	fn roles(&self, source: Argb, is_dark: bool) -> [(String, Argb); 4] {
+		let mut palette = CorePalette::of(self.value(source)).a1().clone();
+		let tones = if is_dark {
+			[80, 20, 30, 90]
+		} else {
+			[40, 100, 90, 10]
+		};
+		let name = camel_case(&self.name.trim().to_lowercase().replace([' ', '-'], "_"));
+		let mut capitalized = name.chars();
+		let capitalized = capitalized
+			.next()
+			.map(|first| first.to_uppercase().chain(capitalized).collect::<String>())
+			.unwrap_or_default();
+		[
+			(name.clone(), palette.tone(tones[0])),
+			(format!("on{capitalized}"), palette.tone(tones[1])),
+			(format!("{name}Container"), palette.tone(tones[2])),
+			(format!("on{capitalized}Container"), palette.tone(tones[3])),
+		]
+	}
+}
+
+impl MaterialTheme {
+	/// Theme of `seed`, like the Theme Builder's default with only a primary
+	/// color.
+	pub fn new(seed: Argb) -> Self {
+		Self::from_core_colors(CoreColors {
+			primary: seed,
+			secondary: None,
+			tertiary: None,
+			neutral: None,
+			neutral_variant: None,
+			error: None,
+		})
+	}
+
+	/// Theme with every core color set.
+	pub fn custom(
+		primary: Argb,
+		secondary: Argb,
+		tertiary: Argb,
+		neutral: Argb,
+		neutral_variant: Argb,
+		error: Argb,
+	) -> Self {
+		Self::from_core_colors(CoreColors {
+			primary,
+			secondary: Some(secondary),
+			tertiary: Some(tertiary),
+			neutral: Some(neutral),
+			neutral_variant: Some(neutral_variant),
+			error: Some(error),
+		})
+	}
+
+	fn from_core_colors(core_colors: CoreColors) -> Self {
+		let mut theme = Self {
+			description: "TYPE: CUSTOM\nMaterial Theme Builder export".to_string(),
+			seed: core_colors.primary,
+			core_colors,
+			extended_colors: Vec::new(),
+			schemes: BTreeMap::new(),
+			palettes: BTreeMap::new(),
+		};
+		let mut core = theme.core_palette();
+
+		for (name, is_dark, contrast_level) in SCHEMES {
+			let mut scheme = DynamicScheme::new(core.clone(), is_dark, contrast_level);
+			let standard = Scheme::from_dynamic_scheme(&mut scheme);
+			let mut roles: BTreeMap<String, Argb> = standard
+				.roles()
+				.into_iter()
+				.chain(surface_container_roles(&mut scheme))
+				.map(|(role, argb)| (camel_case(role), argb))
+				.collect();
+			roles.insert("surfaceTint".to_string(), standard.primary());
+			theme.schemes.insert(name.to_string(), roles);
+		}
+
+		for (name, tonal_palette) in PALETTES {
+			let tones = PALETTE_TONES
+				.iter()
+				.map(|tone| (*tone, tonal_palette(&mut core).tone(*tone)))
+				.collect();
+			theme.palettes.insert(kebab_case(name), tones);
+		}
+
+		theme
+	}
+
+	/// Adds a custom color, with its roles in every scheme.
+	pub fn with_extended_color(mut self, extended_color: ExtendedColor) -> Self {
+		for (name, is_dark, _) in SCHEMES {
+			if let Some(roles) = self.schemes.get_mut(name) {
+				roles.extend(extended_color.roles(self.core_colors.primary, is_dark));
+			}
+		}
+		self.extended_colors.push(extended_color);
+		self
+	}
+
+	pub fn from_json(json: &str) -> Result<Self, ImportError> {
+		Ok(serde_json::from_str(json)?)
+	}
+
+	pub fn to_json(&self) -> String {
+		let mut json = serde_json::to_string_pretty(self).unwrap_or_default();
+		json.push('\n');
+		json
+	}
+
+	pub fn seed(&self) -> Argb {
+		self.seed
+	}
+
+	pub fn extended_colors(&self) -> &[ExtendedColor] {
+		&self.extended_colors
+	}
+
+	/// Palette of the core colors, from [`CorePalette::custom`]. Core colors
+	/// that aren't given keep the palette [`CorePalette::of`] the primary
+	/// color would have.
+	pub fn core_palette(&self) -> CorePalette {
+		let core_colors = &self.core_colors;
+		let primary = core_colors.primary;
+		let mut core = CorePalette::custom(
+			primary,
+			core_colors.secondary.unwrap_or(primary),
+			core_colors.tertiary.unwrap_or(primary),
+			core_colors.neutral.unwrap_or(primary),
+			core_colors.neutral_variant.unwrap_or(primary),
+			core_colors.error.unwrap_or(primary),
+		);
+		let mut default = CorePalette::of(primary);
+		let defaults: [(Option<Argb>, PaletteGetter); 5] = [
+			(core_colors.secondary, CorePalette::a2),
+			(core_colors.tertiary, CorePalette::a3),
+			(core_colors.neutral, CorePalette::n1),
+			(core_colors.neutral_variant, CorePalette::n2),
+			(core_colors.error, CorePalette::error),
+		];
+		for (argb, tonal_palette) in defaults {
+			if argb.is_none() {
+				*tonal_palette(&mut core) = tonal_palette(&mut default).clone();
+			}
+		}
+		core
+	}
+
+	/// Names of the schemes in the document, such as `light` or
+	/// `dark-high-contrast`.
+	pub fn scheme_names(&self) -> impl Iterator<Item = &str> {
+		self.schemes.keys().map(String::as_str)
+	}
+
+	pub fn scheme(&self, name: &str) -> Result<Scheme, ImportError> {
+		let roles = self
+			.schemes
+			.get(name)
+			.ok_or_else(|| ImportError::Missing(format!("schemes.{name}")))?;
+		Scheme::from_roles(|role| {
+			let role = camel_case(role);
+			roles
+				.get(&role)
+				.copied()
+				.ok_or_else(|| ImportError::Missing(format!("schemes.{name}.{role}")))
+		})
+	}
+}
@@ -0,0 +1,225 @@
+use super::{contrasting_tone, escape, PaletteGetter};
+use crate::{
+	palettes::CorePalette,
+	scheme::Scheme,
+	utils::{
+		color::{lstar_from_argb, Argb},
+		string::css_from_argb,
+	},
+};
+use std::fmt::Write;
+
+/// Contrast every syntax token keeps against the editor background.
+const TOKEN_CONTRAST: f64 = 4.5;
+
+/// Syntax tokens: scopes, palette, preferred tone in dark and light themes,
+/// and font style.
+const TOKENS: [(&[&str], PaletteGetter, f64, f64, &str); 9] = [
+	(
+		&["comment", "punctuation.definition.comment"],
+		CorePalette::n2,
+		60.0,
+		50.0,
+		"italic",
+	),
+	(
+		&["keyword", "storage.type", "storage.modifier"],
+		CorePalette::a1,
+		80.0,
+		40.0,
+		"",
+	),
+	(
+		&["entity.name.function", "support.function"],
+		CorePalette::a1,
+		90.0,
+		30.0,
+		"",
+	),
+	(
+		&[
+			"entity.name.type",
+			"entity.name.class",
+			"support.type",
+			"support.class",
+		],
+		CorePalette::a2,
+		80.0,
+		40.0,
+		"",
+	),
+	(
+		&["variable.parameter"],
+		CorePalette::a2,
+		90.0,
+		30.0,
+		"italic",
+	),
+	(
+		&["string", "string.quoted"],
+		CorePalette::a3,
+		80.0,
+		40.0,
+		"",
+	),
+	(
+		&[
+			"constant.numeric",
+			"constant.language",
+			"constant.character",
+		],
+		CorePalette::a3,
+		70.0,
+		50.0,
+		"",
+	),
+	(
+		&["variable", "meta.definition.variable"],
+		CorePalette::n1,
+		90.0,
+		10.0,
+		"",
+	),
+	(
+		&["invalid", "invalid.illegal"],
+		CorePalette::error,
+		80.0,
+		40.0,
+		"",
+	),
+];
+
+/// Generates a VS Code color theme from a [`Scheme`], with syntax colors
+/// taken from the [`CorePalette`] it comes from.
+///
+/// Whether it is dark follows the scheme's surface. Syntax colors move away
+/// from it until they reach 4.5:1, toward the other side when they can't.
+#[derive(Clone, Debug)]
+pub struct VsCodeTheme {
+	scheme: Scheme,
+	palette: CorePalette,
+	name: String,
+}
+
+impl VsCodeTheme {
+	pub fn new(scheme: &Scheme, palette: &CorePalette) -> Self {
+		Self {
+			scheme: scheme.clone(),
+			palette: palette.clone(),
+			name: "Material".to_string(),
+		}
+	}
+
+	/// Name of the theme, `Material` by default.
+	pub fn with_name(mut self, name: &str) -> Self {
+		self.name = name.to_string();
+		self
+	}
+
+	pub fn is_dark(&self) -> bool {
+		lstar_from_argb(self.scheme.surface()) < 50.0
+	}
+
+	/// Workbench colors, as VS Code keys and their colors.
+	pub fn colors(&self) -> Vec<(&'static str, Argb)> {
+		let s = &self.scheme;
+		vec![
+			("focusBorder", s.primary()),
+			("foreground", s.on_surface()),
+			("errorForeground", s.error()),
+			("editor.background", s.surface()),
+			("editor.foreground", s.on_surface()),
+			("editor.selectionBackground", s.primary_container()),
+			("editor.selectionForeground", s.on_primary_container()),
+			("editor.lineHighlightBackground", s.surface_variant()),
+			("editorCursor.foreground", s.primary()),
+			("editorLineNumber.foreground", s.outline()),
+			("editorLineNumber.activeForeground", s.on_surface_variant()),
+			("editorError.foreground", s.error()),
+			("editorWidget.background", s.surface_variant()),
+			("editorWidget.foreground", s.on_surface_variant()),
+			("activityBar.background", s.surface_variant()),
+			("activityBar.foreground", s.on_surface_variant()),
+			("activityBarBadge.background", s.primary()),
+			("activityBarBadge.foreground", s.on_primary()),
+			("sideBar.background", s.surface_variant()),
+			("sideBar.foreground", s.on_surface_variant()),
+			("statusBar.background", s.primary_container()),
+			("statusBar.foreground", s.on_primary_container()),
+			("titleBar.activeBackground", s.surface_variant()),
+			("titleBar.activeForeground", s.on_surface_variant()),
+			("tab.activeBackground", s.surface()),
+			("tab.activeForeground", s.on_surface()),
+			("tab.inactiveBackground", s.surface_variant()),
+			("tab.inactiveForeground", s.on_surface_variant()),
+			("button.background", s.primary()),
+			("button.foreground", s.on_primary()),
+			("button.secondaryBackground", s.secondary_container()),
+			("button.secondaryForeground", s.on_secondary_container()),
+			("input.background", s.surface_variant()),
+			("input.foreground", s.on_surface_variant()),
+			("input.border", s.outline()),
+			("badge.background", s.tertiary_container()),
+			("badge.foreground", s.on_tertiary_container()),
+			("panel.border", s.outline_variant()),
+			("terminal.background", s.surface()),
+			("terminal.foreground", s.on_surface()),
+		]
+	}
+
+	/// Syntax token scopes with their colors and font style.
+	pub fn token_colors(&self) -> Vec<(&'static [&'static str], Argb, &'static str)> {
+		let is_dark = self.is_dark();
+		let background_tone = lstar_from_argb(self.scheme.surface());
+		let mut palette = self.palette.clone();
+
+		TOKENS
+			.iter()
+			.map(
+				|(scopes, tonal_palette, dark_tone, light_tone, font_style)| {
+					let tone = if is_dark { dark_tone } else { light_tone };
+					let tone = contrasting_tone(*tone, background_tone, TOKEN_CONTRAST);
+					let argb = tonal_palette(&mut palette).tone(tone as u8);
+					(*scopes, argb, *font_style)
+				},
+			)
+			.collect()
+	}
+
+	/// The theme file, as VS Code's `*-color-theme.json`.
+	pub fn export(&self) -> String {
+		let mut json = format!(
+			"{{\n  \"name\": \"{}\",\n  \"type\": \"{}\",\n  \"colors\": {{",
+			escape(&self.name),
+			if self.is_dark() { "dark" } else { "light" }
+		);
+		for (i, (key, argb)) in self.colors().into_iter().enumerate() {
+			let separator = if i == 0 { "" } else { "," };
+			let _ = write!(
+				json,
+				"{separator}\n    \"{key}\": \"{}\"",
+				css_from_argb(argb)
+			);
+		}
+		json.push_str("\n  },\n  \"tokenColors\": [");
+		for (i, (scopes, argb, font_style)) in self.token_colors().into_iter().enumerate() {
+			let separator = if i == 0 { "" } else { "," };
+			let scopes = scopes
+				.iter()
+				.map(|scope| format!("\"{scope}\""))
+				.collect::<Vec<_>>()
+				.join(", ");
+			let _ = write!(
+				json,
+				"{separator}\n    {{\n      \"scope\": [{scopes}],\n      \"settings\": {{\n        \"foreground\": \"{}\"",
+				css_from_argb(argb)
+			);
+			if !font_style.is_empty() {
+				let _ = write!(json, ",\n        \"fontStyle\": \"{font_style}\"");
+			}
+			json.push_str("\n      }\n    }");
+		}
+		json.push_str("\n  ]\n}\n");
+		json
+	}
+}
@@ -0,0 +1,81 @@
+use super::camel_case;
+use crate::{scheme::Scheme, utils::color::Argb};
+use std::{fs, io, path::Path};
+
+const INFO: &str = "  \"info\" : {\n    \"author\" : \"xcode\",\n    \"version\" : 1\n  }";
+
+/// Writes a light and dark [`Scheme`] as an Xcode asset catalog, with one
+/// color set per role holding both appearances.
+///
+/// Color sets are named after the role in camelCase, so `on_primary` is
+/// `Color("onPrimary")` in SwiftUI.
+#[derive(Clone, Debug)]
+pub struct XcassetsExporter {
+	light: Scheme,
+	dark: Scheme,
+	catalog_name: String,
+}
+
+impl XcassetsExporter {
+	pub fn new(light: &Scheme, dark: &Scheme) -> Self {
+		Self {
+			light: light.clone(),
+			dark: dark.clone(),
+			catalog_name: "Colors".to_string(),
+		}
+	}
+
+	/// Name of the catalog folder, without `.xcassets`. `Colors` by default.
+	pub fn with_catalog_name(mut self, catalog_name: &str) -> Self {
+		self.catalog_name = catalog_name.to_string();
+		self
+	}
+
+	/// Every `Contents.json` with its path, starting with the catalog's.
+	pub fn files(&self) -> Vec<(String, String)> {
+		let catalog = format!("{}.xcassets", self.catalog_name);
+		let mut files = vec![(
+			format!("{catalog}/Contents.json"),
+			format!("{{\n{INFO}\n}}\n"),
+		)];
+
+		for ((role, light), (_, dark)) in self.light.roles().into_iter().zip(self.dark.roles()) {
+			files.push((
+				format!("{catalog}/{}.colorset/Contents.json", camel_case(role)),
+				color_set(light, dark),
+			));
+		}
+
+		files
+	}
+
+	/// Writes the catalog into `directory`, creating folders as needed.
+	pub fn write_to(&self, directory: &Path) -> io::Result<()> {
+		for (path, contents) in self.files() {
+			let path = directory.join(path);
+			if let Some(parent) = path.parent() {
+				fs::create_dir_all(parent)?;
+			}
+			fs::write(path, contents)?;
+		}
+		Ok(())
+	}
+}
+
+fn color_set(light: Argb, dark: Argb) -> String {
+	format!(
+		"{{\n  \"colors\" : [\n    {{\n{}      \"idiom\" : \"universal\"\n    }},\n    {{\n      \"appearances\" : [\n        {{\n          \"appearance\" : \"luminosity\",\n          \"value\" : \"dark\"\n        }}\n      ],\n{}      \"idiom\" : \"universal\"\n    }}\n  ],\n{INFO}\n}}\n",
+		color(light),
+		color(dark)
+	)
+}
+
+fn color(argb: Argb) -> String {
+	format!(
+		"      \"color\" : {{\n        \"color-space\" : \"srgb\",\n        \"components\" : {{\n          \"alpha\" : \"{:.3}\",\n          \"blue\" : \"0x{:02X}\",\n          \"green\" : \"0x{:02X}\",\n          \"red\" : \"0x{:02X}\"\n        }}\n      }},\n",
+		argb.alpha() as f64 / 255.0,
+		argb.blue(),
+		argb.green(),
+		argb.red()
+	)
+}
@@ -1,6 +1,6 @@
 use super::{viewing_conditions::ViewingConditions, CAM16RGB_TO_XYZ};
 use crate::utils::{
-	color::{argb_from_xyz, linearized, ARGB},
+	color::{argb_from_xyz, linearized, Argb},
 	math::matrix_multiply,
 };
 use std::f64::consts::PI;
@@ -88,12 +88,15 @@ impl Cam16 {
 		1.41 * d_e_prime.powf(0.63)
 	}
 
-	pub fn from_argb(argb: ARGB) -> Self {
-		Self::from_int_in_viewing_conditions(argb, ViewingConditions::default())
+	pub fn from_argb(argb: Argb) -> Self {
+		Self::from_int_in_viewing_conditions(argb, &ViewingConditions::default())
 	}
 
-	fn from_int_in_viewing_conditions(argb: ARGB, viewing_conditions: ViewingConditions) -> Self {
-		let (red, green, blue) = (argb[1], argb[2], argb[3]);
+	pub fn from_int_in_viewing_conditions(
+		argb: Argb,
+		viewing_conditions: &ViewingConditions,
+	) -> Self {
+		let (red, green, blue) = (argb.red(), argb.green(), argb.blue());
 
 		let red_l = linearized(red);
 		let green_l = linearized(green);
@@ -103,6 +106,15 @@ impl Cam16 {
 		let y = 0.2126 * red_l + 0.7152 * green_l + 0.0722 * blue_l;
 		let z = 0.01932141 * red_l + 0.11916382 * green_l + 0.95034478 * blue_l;
 
+		Self::from_xyz_in_viewing_conditions(x, y, z, viewing_conditions)
+	}
+
+	pub fn from_xyz_in_viewing_conditions(
+		x: f64,
+		y: f64,
+		z: f64,
+		viewing_conditions: &ViewingConditions,
+	) -> Self {
 		let r_c = 0.401288 * x + 0.650173 * y - 0.051461 * z;
 		let g_c = -0.250268 * x + 1.204414 * y + 0.045854 * z;
 		let b_c = -0.002079 * x + 0.048952 * y + 0.953127 * z;
@@ -173,14 +185,14 @@ impl Cam16 {
 	}
 
 	pub fn from_jch(j: f64, c: f64, h: f64) -> Self {
-		Self::from_jch_in_viewing_conditions(j, c, h, ViewingConditions::default())
+		Self::from_jch_in_viewing_conditions(j, c, h, &ViewingConditions::default())
 	}
 
-	fn from_jch_in_viewing_conditions(
+	pub fn from_jch_in_viewing_conditions(
 		j: f64,
 		c: f64,
 		h: f64,
-		viewing_conditions: ViewingConditions,
+		viewing_conditions: &ViewingConditions,
 	) -> Self {
 		let q = (4.0 / viewing_conditions.c())
 			* (j / 100.0).sqrt()
@@ -207,14 +219,14 @@ impl Cam16 {
 	}
 
 	pub fn from_ucs(jstar: f64, astar: f64, bstar: f64) -> Self {
-		Self::from_ucs_in_viewing_conditions(jstar, astar, bstar, ViewingConditions::default())
+		Self::from_ucs_in_viewing_conditions(jstar, astar, bstar, &ViewingConditions::default())
 	}
 
-	fn from_ucs_in_viewing_conditions(
+	pub fn from_ucs_in_viewing_conditions(
 		jstar: f64,
 		astar: f64,
 		bstar: f64,
-		viewing_conditions: ViewingConditions,
+		viewing_conditions: &ViewingConditions,
 	) -> Self {
 		let m = (astar.powi(2) + bstar.powi(2)).sqrt();
 
@@ -232,11 +244,16 @@ impl Cam16 {
 		Cam16::from_jch_in_viewing_conditions(j, c, h, viewing_conditions)
 	}
 
-	pub fn to_int(&self) -> ARGB {
+	pub fn to_int(&self) -> Argb {
 		self.into()
 	}
 
-	fn viewed(&self, viewing_conditions: ViewingConditions) -> ARGB {
+	pub fn viewed(&self, viewing_conditions: &ViewingConditions) -> Argb {
+		let [x, y, z] = self.xyz_in_viewing_conditions(viewing_conditions);
+		argb_from_xyz(x, y, z)
+	}
+
+	pub fn xyz_in_viewing_conditions(&self, viewing_conditions: &ViewingConditions) -> [f64; 3] {
 		let alpha = if self.chroma() == 0.0 || self.j() == 0.0 {
 			0.0
 		} else {
@@ -279,14 +296,12 @@ impl Cam16 {
 		let g_f = g_c / viewing_conditions.rgb_d()[1];
 		let b_f = b_c / viewing_conditions.rgb_d()[2];
 
-		let argb = matrix_multiply([r_f, g_f, b_f], CAM16RGB_TO_XYZ);
-
-		argb_from_xyz(argb[0], argb[1], argb[2])
+		matrix_multiply([r_f, g_f, b_f], CAM16RGB_TO_XYZ)
 	}
 }
 
-impl From<&Cam16> for ARGB {
-	fn from(cam16: &Cam16) -> ARGB {
-		cam16.viewed(ViewingConditions::default())
+impl From<&Cam16> for Argb {
+	fn from(cam16: &Cam16) -> Argb {
+		cam16.viewed(&ViewingConditions::default())
 	}
 }
@@ -1,7 +1,8 @@
+use super::XYZ_TO_CAM16RGB;
 use crate::{
 	hct::{cam16::Cam16, viewing_conditions::ViewingConditions},
 	utils::{
-		color::{argb_from_linrgb, argb_from_lstar, y_from_lstar, ARGB},
+		color::{argb_from_linrgb, argb_from_lstar, y_from_lstar, Argb, SRGB_TO_XYZ},
 		math::{matrix_multiply, sanitize_degrees_double},
 	},
 };
@@ -33,6 +34,50 @@ const LINRGB_FROM_SCALED_DISCOUNT: [[f64; 3]; 3] = [
 
 const Y_FROM_LINRGB: [f64; 3] = [0.2126, 0.7152, 0.0722];
 
+// The viewing conditions the solver works in, along with the matrices going
+// between linear RGB and the scaled, discounted CAM16 RGB they imply.
+struct Conditions<'a> {
+	viewing_conditions: &'a ViewingConditions,
+	scaled_discount_from_linrgb: [[f64; 3]; 3],
+	linrgb_from_scaled_discount: [[f64; 3]; 3],
+	// Y grows about as fast as J to this power, for Newton's method.
+	y_exponent: f64,
+}
+
+impl<'a> Conditions<'a> {
+	fn new(viewing_conditions: &'a ViewingConditions) -> Self {
+		let rgb_d = viewing_conditions.rgb_d();
+		let fl = viewing_conditions.fl();
+		let mut scaled_discount_from_linrgb = [[0.0; 3]; 3];
+		for (i, row) in scaled_discount_from_linrgb.iter_mut().enumerate() {
+			for (j, value) in row.iter_mut().enumerate() {
+				let cam16rgb_from_linrgb = (0..3)
+					.map(|k| XYZ_TO_CAM16RGB[i][k] * SRGB_TO_XYZ[k][j])
+					.sum::<f64>();
+				*value = rgb_d[i] * fl / 100.0 * cam16rgb_from_linrgb;
+			}
+		}
+
+		Self {
+			viewing_conditions,
+			scaled_discount_from_linrgb,
+			linrgb_from_scaled_discount: matrix_inverse(scaled_discount_from_linrgb),
+			y_exponent: 1.0 / (0.42 * viewing_conditions.c() * viewing_conditions.z()),
+		}
+	}
+
+	// Uses the precomputed values, so the default conditions don't pay for
+	// them nor for their rounding errors.
+	fn with_default_matrices(viewing_conditions: &'a ViewingConditions) -> Self {
+		Self {
+			viewing_conditions,
+			scaled_discount_from_linrgb: SCALED_DISCOUNT_FROM_LINRGB,
+			linrgb_from_scaled_discount: LINRGB_FROM_SCALED_DISCOUNT,
+			y_exponent: 2.0,
+		}
+	}
+}
+
 const CRITICAL_PLANES: [f64; 255] = [
 	0.015176349177441876,
 	0.045529047532325624,
@@ -291,6 +336,22 @@ const CRITICAL_PLANES: [f64; 255] = [
 	99.55452497210776,
 ];
 
+fn matrix_inverse(m: [[f64; 3]; 3]) -> [[f64; 3]; 3] {
+	let cofactor = |i: usize, j: usize| {
+		let (r0, r1) = ((i + 1) % 3, (i + 2) % 3);
+		let (c0, c1) = ((j + 1) % 3, (j + 2) % 3);
+		m[r0][c0] * m[r1][c1] - m[r0][c1] * m[r1][c0]
+	};
+	let determinant = (0..3).map(|j| m[0][j] * cofactor(0, j)).sum::<f64>();
+	let mut inverse = [[0.0; 3]; 3];
+	for (i, row) in inverse.iter_mut().enumerate() {
+		for (j, value) in row.iter_mut().enumerate() {
+			*value = cofactor(j, i) / determinant;
+		}
+	}
+	inverse
+}
+
 fn sanitize_radians(angle: f64) -> f64 {
 	(angle + PI * 8.0) % (PI * 2.0)
 }
@@ -310,8 +371,8 @@ fn chromatic_adaptation(component: f64) -> f64 {
 	component.signum() * 400.0 * af / (af + 27.13)
 }
 
-fn hue_of(linrgb: [f64; 3]) -> f64 {
-	let scaled_discount = matrix_multiply(linrgb, SCALED_DISCOUNT_FROM_LINRGB);
+fn hue_of(linrgb: [f64; 3], conditions: &Conditions) -> f64 {
+	let scaled_discount = matrix_multiply(linrgb, conditions.scaled_discount_from_linrgb);
 	let r_a = chromatic_adaptation(scaled_discount[0]);
 	let g_a = chromatic_adaptation(scaled_discount[1]);
 	let b_a = chromatic_adaptation(scaled_discount[2]);
@@ -354,7 +415,7 @@ fn nth_vertex(y: f64, n: usize) -> [f64; 3] {
 	let k_g = Y_FROM_LINRGB[1];
 	let k_b = Y_FROM_LINRGB[2];
 	let coord_a = if n % 4 <= 1 { 0.0 } else { 100.0 };
-	let coord_b = if n % 2 == 0 { 0.0 } else { 100.0 };
+	let coord_b = if n.is_multiple_of(2) { 0.0 } else { 100.0 };
 	if n < 4 {
 		let g = coord_a;
 		let b = coord_b;
@@ -385,7 +446,7 @@ fn nth_vertex(y: f64, n: usize) -> [f64; 3] {
 	}
 }
 
-fn bisect_to_segment(y: f64, target_hue: f64) -> [[f64; 3]; 2] {
+fn bisect_to_segment(y: f64, target_hue: f64, conditions: &Conditions) -> [[f64; 3]; 2] {
 	let mut left = [-1.0, -1.0, -1.0];
 	let mut right = left;
 	let mut left_hue = 0.0;
@@ -397,7 +458,7 @@ fn bisect_to_segment(y: f64, target_hue: f64) -> [[f64; 3]; 2] {
 		if mid[0] < 0.0 {
 			continue;
 		}
-		let mid_hue = hue_of(mid);
+		let mid_hue = hue_of(mid, conditions);
 		if !initialized {
 			left = mid;
 			right = mid;
@@ -437,10 +498,10 @@ fn critical_plane_above(x: f64) -> u8 {
 	(x - 0.5).ceil() as u8
 }
 
-fn bisect_to_limit(y: f64, target_hue: f64) -> [f64; 3] {
-	let segment = bisect_to_segment(y, target_hue);
+fn bisect_to_limit(y: f64, target_hue: f64, conditions: &Conditions) -> [f64; 3] {
+	let segment = bisect_to_segment(y, target_hue, conditions);
 	let mut left = segment[0];
-	let mut left_hue = hue_of(left);
+	let mut left_hue = hue_of(left, conditions);
 	let mut right = segment[1];
 	for axis in 0..3 {
 		if left[axis] != right[axis] {
@@ -463,7 +524,7 @@ fn bisect_to_limit(y: f64, target_hue: f64) -> [f64; 3] {
 					let m_plane = ((l_plane as f32 + r_plane as f32) / 2.0).floor() as u8;
 					let mid_plane_coordinate = CRITICAL_PLANES[m_plane as usize];
 					let mid = set_coordinate(left, mid_plane_coordinate, right, axis);
-					let mid_hue = hue_of(mid);
+					let mid_hue = hue_of(mid, conditions);
 					if are_in_cyclic_order(left_hue, target_hue, mid_hue) {
 						right = mid;
 						r_plane = m_plane;
@@ -485,13 +546,18 @@ fn inverse_chromatic_adaptation(adapted: f64) -> f64 {
 	adapted.signum() * base.powf(1.0 / 0.42)
 }
 
-fn find_result_by_j(hue_radians: f64, chroma: f64, y: f64) -> ARGB {
+fn find_result_by_j(
+	hue_radians: f64,
+	chroma: f64,
+	y: f64,
+	conditions: &Conditions,
+) -> Option<Argb> {
 	// Initial estimate of j.
 	let mut j = y.sqrt() * 11.0;
 	// ===========================================================
 	// Operations inlined from Cam16 to avoid repeated calculation
 	// ===========================================================
-	let viewing_conditions = ViewingConditions::default();
+	let viewing_conditions = conditions.viewing_conditions;
 	let t_inner_coeff = 1.0 / (1.64 - 0.29f64.powf(viewing_conditions.n())).powf(0.73);
 	let e_hue = 0.25 * ((hue_radians + 2.0).cos() + 3.8);
 	let p1 = e_hue * (50000.0 / 13.0) * viewing_conditions.nc() * viewing_conditions.ncb();
@@ -523,50 +589,87 @@ fn find_result_by_j(hue_radians: f64, chroma: f64, y: f64) -> ARGB {
 		let b_cscaled = inverse_chromatic_adaptation(b_a);
 		let linrgb = matrix_multiply(
 			[r_cscaled, g_cscaled, b_cscaled],
-			LINRGB_FROM_SCALED_DISCOUNT,
+			conditions.linrgb_from_scaled_discount,
 		);
 		// ===========================================================
 		// Operations inlined from Cam16 to avoid repeated calculation
 		// ===========================================================
 		if linrgb[0] < 0.0 || linrgb[1] < 0.0 || linrgb[2] < 0.0 {
-			return [0, 0, 0, 0];
+			return None;
 		}
 		let k_r = Y_FROM_LINRGB[0];
 		let k_g = Y_FROM_LINRGB[1];
 		let k_b = Y_FROM_LINRGB[2];
 		let fnj = k_r * linrgb[0] + k_g * linrgb[1] + k_b * linrgb[2];
 		if fnj <= 0.0 {
-			return [0, 0, 0, 0];
+			return None;
 		}
 		if iteration_round == 4 || (fnj - y).abs() < 0.002 {
 			if linrgb[0] > 100.01 || linrgb[1] > 100.01 || linrgb[2] > 100.01 {
-				return [0, 0, 0, 0];
+				return None;
 			}
-			return argb_from_linrgb(linrgb);
+			return Some(argb_from_linrgb(linrgb));
 		}
 		// Iterates with Newton method,
-		// Using 2 * fn(j) / j as the approximation of fn'(j)
-		j = j - (fnj - y) * j / (2.0 * fnj);
+		// Using y_exponent * fn(j) / j as the approximation of fn'(j)
+		j = j - (fnj - y) * j / (conditions.y_exponent * fnj);
 	}
 
-	[0, 0, 0, 0]
+	None
 }
 
-pub fn solve_to_int(hue_degrees: f64, chroma: f64, lstar: f64) -> ARGB {
+pub fn solve_to_int(hue_degrees: f64, chroma: f64, lstar: f64) -> Argb {
+	let viewing_conditions = ViewingConditions::default();
+	solve(
+		hue_degrees,
+		chroma,
+		lstar,
+		&Conditions::with_default_matrices(&viewing_conditions),
+	)
+}
+
+pub fn solve_to_cam(hue_degrees: f64, chroma: f64, lstar: f64) -> Cam16 {
+	Cam16::from_argb(solve_to_int(hue_degrees, chroma, lstar))
+}
+
+/// Like [`solve_to_int`], with `hue_degrees` and `chroma` as seen in
+/// `viewing_conditions`.
+pub fn solve_to_int_in(
+	hue_degrees: f64,
+	chroma: f64,
+	lstar: f64,
+	viewing_conditions: &ViewingConditions,
+) -> Argb {
+	let conditions = if *viewing_conditions == ViewingConditions::default() {
+		Conditions::with_default_matrices(viewing_conditions)
+	} else {
+		Conditions::new(viewing_conditions)
+	};
+	solve(hue_degrees, chroma, lstar, &conditions)
+}
+
+pub fn solve_to_cam_in(
+	hue_degrees: f64,
+	chroma: f64,
+	lstar: f64,
+	viewing_conditions: &ViewingConditions,
+) -> Cam16 {
+	Cam16::from_int_in_viewing_conditions(
+		solve_to_int_in(hue_degrees, chroma, lstar, viewing_conditions),
+		viewing_conditions,
+	)
+}
+
+fn solve(hue_degrees: f64, chroma: f64, lstar: f64, conditions: &Conditions) -> Argb {
 	if chroma < 0.0001 || !(0.0001..=99.9999).contains(&lstar) {
 		return argb_from_lstar(lstar);
 	}
 	let hue_degrees = sanitize_degrees_double(hue_degrees);
 	let hue_radians = hue_degrees / 180.0 * PI;
 	let y = y_from_lstar(lstar);
-	let exact_answer = find_result_by_j(hue_radians, chroma, y);
-	if exact_answer != [0, 0, 0, 0] {
+	if let Some(exact_answer) = find_result_by_j(hue_radians, chroma, y, conditions) {
 		return exact_answer;
 	}
-	let linrgb = bisect_to_limit(y, hue_radians);
+	let linrgb = bisect_to_limit(y, hue_radians, conditions);
 	argb_from_linrgb(linrgb)
 }
-
-pub fn solve_to_cam(hue_degrees: f64, chroma: f64, lstar: f64) -> Cam16 {
-	Cam16::from_argb(solve_to_int(hue_degrees, chroma, lstar))
-}
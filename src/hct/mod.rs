@@ -1,30 +1,35 @@
 use crate::{
-	hct::cam16::Cam16,
-	utils::color::{lstar_from_argb, ARGB},
+	hct::{cam16::Cam16, viewing_conditions::ViewingConditions},
+	utils::color::{lstar_from_argb, lstar_from_y, Argb},
 };
 
 pub mod cam16;
 pub mod hct_solver;
 pub mod viewing_conditions;
 
-pub(self) const XYZ_TO_CAM16RGB: [[f64; 3]; 3] = [
+const XYZ_TO_CAM16RGB: [[f64; 3]; 3] = [
 	[0.401288, 0.650173, -0.051461],
 	[-0.250268, 1.204414, 0.045854],
 	[-0.002079, 0.048952, 0.953127],
 ];
 
-pub(self) const CAM16RGB_TO_XYZ: [[f64; 3]; 3] = [
+const CAM16RGB_TO_XYZ: [[f64; 3]; 3] = [
 	[1.8620678, -1.0112547, 0.14918678],
 	[0.38752654, 0.62144744, -0.00897398],
 	[-0.01584150, -0.03412294, 1.0499644],
 ];
 
-#[derive(Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(
+	feature = "serde",
+	derive(serde::Serialize, serde::Deserialize),
+	serde(from = "HctFields", into = "HctFields")
+)]
 pub struct Hct {
 	hue: f64,
 	chroma: f64,
 	tone: f64,
-	argb: ARGB,
+	argb: Argb,
 }
 
 impl Hct {
@@ -34,7 +39,7 @@ impl Hct {
 		hct
 	}
 
-	pub fn from_argb(argb: ARGB) -> Self {
+	pub fn from_argb(argb: Argb) -> Self {
 		let mut hct = Hct::default();
 		hct.mut_internal_state(argb);
 		hct
@@ -50,11 +55,11 @@ impl Hct {
 		self.tone
 	}
 
-	pub fn argb(&self) -> ARGB {
+	pub fn argb(&self) -> Argb {
 		self.argb
 	}
 
-	pub fn to_int(&self) -> ARGB {
+	pub fn to_int(&self) -> Argb {
 		self.argb()
 	}
 
@@ -70,7 +75,16 @@ impl Hct {
 		self.mut_internal_state(hct_solver::solve_to_int(self.hue(), self.chroma(), tone))
 	}
 
-	fn mut_internal_state(&mut self, argb: ARGB) {
+	/// The color that looks, in the default viewing conditions, like this
+	/// one does in `viewing_conditions`.
+	pub fn in_viewing_conditions(&self, viewing_conditions: &ViewingConditions) -> Hct {
+		let cam = Cam16::from_argb(self.to_int());
+		let [x, y, z] = cam.xyz_in_viewing_conditions(viewing_conditions);
+		let recast = Cam16::from_xyz_in_viewing_conditions(x, y, z, &ViewingConditions::default());
+		Hct::from(recast.hue(), recast.chroma(), lstar_from_y(y))
+	}
+
+	fn mut_internal_state(&mut self, argb: Argb) {
 		self.argb = argb;
 		let cam = Cam16::from_argb(argb);
 		self.hue = cam.hue();
@@ -78,3 +92,37 @@ impl Hct {
 		self.tone = lstar_from_argb(argb);
 	}
 }
+
+/// Serialized form of [`Hct`]. `argb`, when present, is what gets restored,
+/// since solving hue, chroma and tone again may land on a neighbouring color.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct HctFields {
+	hue: f64,
+	chroma: f64,
+	tone: f64,
+	#[serde(default)]
+	argb: Option<Argb>,
+}
+
+#[cfg(feature = "serde")]
+impl From<Hct> for HctFields {
+	fn from(hct: Hct) -> Self {
+		Self {
+			hue: hct.hue,
+			chroma: hct.chroma,
+			tone: hct.tone,
+			argb: Some(hct.argb),
+		}
+	}
+}
+
+#[cfg(feature = "serde")]
+impl From<HctFields> for Hct {
+	fn from(fields: HctFields) -> Self {
+		match fields.argb {
+			Some(argb) => Hct::from_argb(argb),
+			None => Hct::from(fields.hue, fields.chroma, fields.tone),
+		}
+	}
+}
@@ -5,7 +5,12 @@ use crate::utils::{
 };
 use std::f64::consts::PI;
 
-#[derive(Debug)]
+/// The environment a color is seen in, as modeled by CAM16.
+///
+/// [`ViewingConditions::default`] matches sRGB's intended surroundings, a
+/// 50 L* gray background under D65. Other environments are made with
+/// [`ViewingConditions::builder`].
+#[derive(Clone, Debug, PartialEq)]
 pub struct ViewingConditions {
 	aw: f64,
 	nbb: f64,
@@ -20,6 +25,10 @@ pub struct ViewingConditions {
 }
 
 impl ViewingConditions {
+	pub fn builder() -> ViewingConditionsBuilder {
+		ViewingConditionsBuilder::default()
+	}
+
 	fn new(
 		white_point: [f64; 3],
 		adapting_luminance: f64,
@@ -27,6 +36,9 @@ impl ViewingConditions {
 		surround: f64,
 		discounting_illuminant: bool,
 	) -> Self {
+		// Avoid dividing by zero in the calculations depending on `n`.
+		let background_lstar = background_lstar.max(0.1);
+
 		let [r_w, g_w, b_w] = matrix_multiply(white_point, XYZ_TO_CAM16RGB);
 
 		let f = 0.8 + surround / 10.0;
@@ -130,12 +142,71 @@ impl ViewingConditions {
 
 impl Default for ViewingConditions {
 	fn default() -> Self {
-		Self::new(
-			white_point_d65(),
-			200.0 / PI * y_from_lstar(50.0) / 100.0,
-			50.0,
-			2.0,
-			false,
+		ViewingConditionsBuilder::default().build()
+	}
+}
+
+/// Builds [`ViewingConditions`], starting from the default ones.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ViewingConditionsBuilder {
+	white_point: [f64; 3],
+	adapting_luminance: f64,
+	background_lstar: f64,
+	surround: f64,
+	discounting_illuminant: bool,
+}
+
+impl Default for ViewingConditionsBuilder {
+	fn default() -> Self {
+		Self {
+			white_point: white_point_d65(),
+			adapting_luminance: 200.0 / PI * y_from_lstar(50.0) / 100.0,
+			background_lstar: 50.0,
+			surround: 2.0,
+			discounting_illuminant: false,
+		}
+	}
+}
+
+impl ViewingConditionsBuilder {
+	/// XYZ coordinates of white, D65 by default.
+	pub fn white_point(mut self, white_point: [f64; 3]) -> Self {
+		self.white_point = white_point;
+		self
+	}
+
+	/// Light strength in lux.
+	pub fn adapting_luminance(mut self, adapting_luminance: f64) -> Self {
+		self.adapting_luminance = adapting_luminance;
+		self
+	}
+
+	/// Average L* of the area surrounding the color.
+	pub fn background_lstar(mut self, background_lstar: f64) -> Self {
+		self.background_lstar = background_lstar;
+		self
+	}
+
+	/// From 0.0 (dark) through 1.0 (dim) to 2.0 (average).
+	pub fn surround(mut self, surround: f64) -> Self {
+		self.surround = surround;
+		self
+	}
+
+	/// Whether the eye fully adapted to the illuminant, as when looking at a
+	/// self-luminous display.
+	pub fn discounting_illuminant(mut self, discounting_illuminant: bool) -> Self {
+		self.discounting_illuminant = discounting_illuminant;
+		self
+	}
+
+	pub fn build(&self) -> ViewingConditions {
+		ViewingConditions::new(
+			self.white_point,
+			self.adapting_luminance,
+			self.background_lstar,
+			self.surround,
+			self.discounting_illuminant,
 		)
 	}
 }
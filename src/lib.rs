@@ -1,4 +1,8 @@
+pub mod blend;
+pub mod dynamic_color;
+pub mod export;
 pub mod hct;
 pub mod palettes;
 pub mod scheme;
+pub mod temperature;
 pub mod utils;
@@ -1,7 +1,8 @@
 use super::tonal::TonalPalette;
-use crate::{hct::Hct, utils::color::ARGB};
+use crate::{hct::Hct, utils::color::Argb};
 
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CorePalette {
 	a1: TonalPalette,
 	a2: TonalPalette,
@@ -13,21 +14,21 @@ pub struct CorePalette {
 
 impl Default for CorePalette {
 	fn default() -> Self {
-		let blue = [255, 0, 0, 255];
+		let blue = Argb::from(0xff0000ff);
 		CorePalette::of(blue)
 	}
 }
 
 impl CorePalette {
-	pub fn of(argb: ARGB) -> Self {
+	pub fn of(argb: Argb) -> Self {
 		Self::new(argb, false)
 	}
 
-	pub fn content_of(argb: ARGB) -> Self {
+	pub fn content_of(argb: Argb) -> Self {
 		Self::new(argb, true)
 	}
 
-	fn new(argb: ARGB, is_content: bool) -> Self {
+	fn new(argb: Argb, is_content: bool) -> Self {
 		let hct = Hct::from_argb(argb);
 		let hue = hct.hue();
 		let chroma = hct.chroma();
@@ -78,7 +79,7 @@ impl CorePalette {
 		&mut self.error
 	}
 
-	pub fn custom(a1: ARGB, a2: ARGB, a3: ARGB, n1: ARGB, n2: ARGB, error: ARGB) -> Self {
+	pub fn custom(a1: Argb, a2: Argb, a3: Argb, n1: Argb, n2: Argb, error: Argb) -> Self {
 		Self {
 			a1: TonalPalette::from_argb(a1),
 			a2: TonalPalette::from_argb(a2),
@@ -1,9 +1,11 @@
-use crate::{hct::Hct, utils::color::ARGB};
+use crate::{hct::Hct, utils::color::Argb};
 use std::collections::HashMap;
 
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TonalPalette {
-	tones: HashMap<u8, ARGB>,
+	#[cfg_attr(feature = "serde", serde(serialize_with = "serialize_tones", default))]
+	tones: HashMap<u8, Argb>,
 	hue: f64,
 	chroma: f64,
 }
@@ -17,7 +19,7 @@ impl TonalPalette {
 		}
 	}
 
-	pub fn from_argb(argb: ARGB) -> Self {
+	pub fn from_argb(argb: Argb) -> Self {
 		let hct = Hct::from_argb(argb);
 		Self::new(hct.hue(), hct.chroma())
 	}
@@ -26,7 +28,7 @@ impl TonalPalette {
 		Self::new(hue, chroma)
 	}
 
-	pub fn tone(&mut self, tone: u8) -> ARGB {
+	pub fn tone(&mut self, tone: u8) -> Argb {
 		if let Some(t) = self.tones.get(&tone) {
 			*t
 		} else {
@@ -36,3 +38,12 @@ impl TonalPalette {
 		}
 	}
 }
+
+/// Writes the cached tones in ascending order, so output is stable.
+#[cfg(feature = "serde")]
+fn serialize_tones<S: serde::Serializer>(
+	tones: &HashMap<u8, Argb>,
+	serializer: S,
+) -> Result<S::Ok, S::Error> {
+	serializer.collect_map(tones.iter().collect::<std::collections::BTreeMap<_, _>>())
+}
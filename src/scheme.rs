@@ -1,127 +1,137 @@
-use crate::{palettes::CorePalette, utils::color::ARGB};
+use crate::{
+	dynamic_color::{DynamicScheme, MaterialDynamicColors, SpecVersion},
+	palettes::CorePalette,
+	utils::color::Argb,
+};
 
+/// With the `serde` feature, serializes as a map of role name to hex color.
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Scheme {
-	primary: ARGB,
-	on_primary: ARGB,
-	primary_container: ARGB,
-	on_primary_container: ARGB,
-	secondary: ARGB,
-	on_secondary: ARGB,
-	secondary_container: ARGB,
-	on_secondary_container: ARGB,
-	tertiary: ARGB,
-	on_tertiary: ARGB,
-	tertiary_container: ARGB,
-	on_tertiary_container: ARGB,
-	error: ARGB,
-	on_error: ARGB,
-	error_container: ARGB,
-	on_error_container: ARGB,
-	background: ARGB,
-	on_background: ARGB,
-	surface: ARGB,
-	on_surface: ARGB,
-	surface_variant: ARGB,
-	on_surface_variant: ARGB,
-	outline: ARGB,
-	outline_variant: ARGB,
-	shadow: ARGB,
-	scrim: ARGB,
-	inverse_surface: ARGB,
-	inverse_on_surface: ARGB,
-	inverse_primary: ARGB,
+	primary: Argb,
+	on_primary: Argb,
+	primary_container: Argb,
+	on_primary_container: Argb,
+	secondary: Argb,
+	on_secondary: Argb,
+	secondary_container: Argb,
+	on_secondary_container: Argb,
+	tertiary: Argb,
+	on_tertiary: Argb,
+	tertiary_container: Argb,
+	on_tertiary_container: Argb,
+	error: Argb,
+	on_error: Argb,
+	error_container: Argb,
+	on_error_container: Argb,
+	background: Argb,
+	on_background: Argb,
+	surface: Argb,
+	on_surface: Argb,
+	surface_variant: Argb,
+	on_surface_variant: Argb,
+	outline: Argb,
+	outline_variant: Argb,
+	shadow: Argb,
+	scrim: Argb,
+	inverse_surface: Argb,
+	inverse_on_surface: Argb,
+	inverse_primary: Argb,
 }
 
 impl Default for Scheme {
 	fn default() -> Self {
-		let blue = [255, 0, 0, 255];
+		let blue = Argb::from(0xff0000ff);
 		Self::light(blue)
 	}
 }
 
 impl Scheme {
-	pub fn light(argb: ARGB) -> Self {
-		Self::light_from_core_palette(&mut CorePalette::of(argb))
+	pub fn light(argb: Argb) -> Self {
+		Self::light_from_core_palette(&CorePalette::of(argb))
 	}
 
-	pub fn dark(argb: ARGB) -> Self {
-		Self::dark_from_core_palette(&mut CorePalette::of(argb))
+	pub fn dark(argb: Argb) -> Self {
+		Self::dark_from_core_palette(&CorePalette::of(argb))
 	}
 
-	pub fn light_content(argb: ARGB) -> Self {
-		Self::light_from_core_palette(&mut CorePalette::content_of(argb))
+	pub fn light_content(argb: Argb) -> Self {
+		Self::light_from_core_palette(&CorePalette::content_of(argb))
 	}
 
-	pub fn dark_content(argb: ARGB) -> Self {
-		Self::dark_from_core_palette(&mut CorePalette::content_of(argb))
+	pub fn dark_content(argb: Argb) -> Self {
+		Self::dark_from_core_palette(&CorePalette::content_of(argb))
 	}
 
-	pub fn light_from_core_palette(core: &mut CorePalette) -> Self {
-		Self::new(
-			core.a1().tone(40),
-			core.a1().tone(100),
-			core.a1().tone(90),
-			core.a1().tone(10),
-			core.a2().tone(40),
-			core.a2().tone(100),
-			core.a2().tone(90),
-			core.a2().tone(10),
-			core.a3().tone(40),
-			core.a3().tone(100),
-			core.a3().tone(90),
-			core.a3().tone(10),
-			core.error().tone(40),
-			core.error().tone(100),
-			core.error().tone(90),
-			core.error().tone(10),
-			core.n1().tone(99),
-			core.n1().tone(10),
-			core.n1().tone(99),
-			core.n1().tone(10),
-			core.n2().tone(90),
-			core.n2().tone(30),
-			core.n2().tone(50),
-			core.n2().tone(80),
-			core.n1().tone(0),
-			core.n1().tone(0),
-			core.n1().tone(20),
-			core.n1().tone(95),
-			core.a1().tone(80),
+	pub fn light_medium_contrast(argb: Argb) -> Self {
+		Self::from_dynamic_scheme(&mut DynamicScheme::new(CorePalette::of(argb), false, 0.5))
+	}
+
+	pub fn light_high_contrast(argb: Argb) -> Self {
+		Self::from_dynamic_scheme(&mut DynamicScheme::new(CorePalette::of(argb), false, 1.0))
+	}
+
+	pub fn dark_medium_contrast(argb: Argb) -> Self {
+		Self::from_dynamic_scheme(&mut DynamicScheme::new(CorePalette::of(argb), true, 0.5))
+	}
+
+	pub fn dark_high_contrast(argb: Argb) -> Self {
+		Self::from_dynamic_scheme(&mut DynamicScheme::new(CorePalette::of(argb), true, 1.0))
+	}
+
+	pub fn light_with_spec_version(argb: Argb, spec_version: SpecVersion) -> Self {
+		Self::from_dynamic_scheme(
+			&mut DynamicScheme::new(CorePalette::of(argb), false, 0.0)
+				.with_spec_version(spec_version),
+		)
+	}
+
+	pub fn dark_with_spec_version(argb: Argb, spec_version: SpecVersion) -> Self {
+		Self::from_dynamic_scheme(
+			&mut DynamicScheme::new(CorePalette::of(argb), true, 0.0)
+				.with_spec_version(spec_version),
 		)
 	}
 
-	pub fn dark_from_core_palette(core: &mut CorePalette) -> Self {
+	pub fn light_from_core_palette(core: &CorePalette) -> Self {
+		Self::from_dynamic_scheme(&mut DynamicScheme::new(core.clone(), false, 0.0))
+	}
+
+	pub fn dark_from_core_palette(core: &CorePalette) -> Self {
+		Self::from_dynamic_scheme(&mut DynamicScheme::new(core.clone(), true, 0.0))
+	}
+
+	pub fn from_dynamic_scheme(scheme: &mut DynamicScheme) -> Self {
 		Self::new(
-			core.a1().tone(80),
-			core.a1().tone(20),
-			core.a1().tone(30),
-			core.a1().tone(90),
-			core.a2().tone(80),
-			core.a2().tone(20),
-			core.a2().tone(30),
-			core.a2().tone(90),
-			core.a3().tone(80),
-			core.a3().tone(20),
-			core.a3().tone(30),
-			core.a3().tone(90),
-			core.error().tone(80),
-			core.error().tone(20),
-			core.error().tone(30),
-			core.error().tone(80),
-			core.n1().tone(10),
-			core.n1().tone(90),
-			core.n1().tone(10),
-			core.n1().tone(90),
-			core.n2().tone(30),
-			core.n2().tone(80),
-			core.n2().tone(60),
-			core.n2().tone(30),
-			core.n1().tone(0),
-			core.n1().tone(0),
-			core.n1().tone(90),
-			core.n1().tone(20),
-			core.a1().tone(40),
+			MaterialDynamicColors::primary().get_argb(scheme),
+			MaterialDynamicColors::on_primary().get_argb(scheme),
+			MaterialDynamicColors::primary_container().get_argb(scheme),
+			MaterialDynamicColors::on_primary_container().get_argb(scheme),
+			MaterialDynamicColors::secondary().get_argb(scheme),
+			MaterialDynamicColors::on_secondary().get_argb(scheme),
+			MaterialDynamicColors::secondary_container().get_argb(scheme),
+			MaterialDynamicColors::on_secondary_container().get_argb(scheme),
+			MaterialDynamicColors::tertiary().get_argb(scheme),
+			MaterialDynamicColors::on_tertiary().get_argb(scheme),
+			MaterialDynamicColors::tertiary_container().get_argb(scheme),
+			MaterialDynamicColors::on_tertiary_container().get_argb(scheme),
+			MaterialDynamicColors::error().get_argb(scheme),
+			MaterialDynamicColors::on_error().get_argb(scheme),
+			MaterialDynamicColors::error_container().get_argb(scheme),
+			MaterialDynamicColors::on_error_container().get_argb(scheme),
+			MaterialDynamicColors::background().get_argb(scheme),
+			MaterialDynamicColors::on_background().get_argb(scheme),
+			MaterialDynamicColors::surface().get_argb(scheme),
+			MaterialDynamicColors::on_surface().get_argb(scheme),
+			MaterialDynamicColors::surface_variant().get_argb(scheme),
+			MaterialDynamicColors::on_surface_variant().get_argb(scheme),
+			MaterialDynamicColors::outline().get_argb(scheme),
+			MaterialDynamicColors::outline_variant().get_argb(scheme),
+			MaterialDynamicColors::shadow().get_argb(scheme),
+			MaterialDynamicColors::scrim().get_argb(scheme),
+			MaterialDynamicColors::inverse_surface().get_argb(scheme),
+			MaterialDynamicColors::inverse_on_surface().get_argb(scheme),
+			MaterialDynamicColors::inverse_primary().get_argb(scheme),
 		)
 	}
 }
@@ -129,35 +139,35 @@ impl Scheme {
 impl Scheme {
 	#[allow(clippy::too_many_arguments)]
 	fn new(
-		primary: ARGB,
-		on_primary: ARGB,
-		primary_container: ARGB,
-		on_primary_container: ARGB,
-		secondary: ARGB,
-		on_secondary: ARGB,
-		secondary_container: ARGB,
-		on_secondary_container: ARGB,
-		tertiary: ARGB,
-		on_tertiary: ARGB,
-		tertiary_container: ARGB,
-		on_tertiary_container: ARGB,
-		error: ARGB,
-		on_error: ARGB,
-		error_container: ARGB,
-		on_error_container: ARGB,
-		background: ARGB,
-		on_background: ARGB,
-		surface: ARGB,
-		on_surface: ARGB,
-		surface_variant: ARGB,
-		on_surface_variant: ARGB,
-		outline: ARGB,
-		outline_variant: ARGB,
-		shadow: ARGB,
-		scrim: ARGB,
-		inverse_surface: ARGB,
-		inverse_on_surface: ARGB,
-		inverse_primary: ARGB,
+		primary: Argb,
+		on_primary: Argb,
+		primary_container: Argb,
+		on_primary_container: Argb,
+		secondary: Argb,
+		on_secondary: Argb,
+		secondary_container: Argb,
+		on_secondary_container: Argb,
+		tertiary: Argb,
+		on_tertiary: Argb,
+		tertiary_container: Argb,
+		on_tertiary_container: Argb,
+		error: Argb,
+		on_error: Argb,
+		error_container: Argb,
+		on_error_container: Argb,
+		background: Argb,
+		on_background: Argb,
+		surface: Argb,
+		on_surface: Argb,
+		surface_variant: Argb,
+		on_surface_variant: Argb,
+		outline: Argb,
+		outline_variant: Argb,
+		shadow: Argb,
+		scrim: Argb,
+		inverse_surface: Argb,
+		inverse_on_surface: Argb,
+		inverse_primary: Argb,
 	) -> Self {
 		Self {
 			primary,
@@ -192,91 +202,175 @@ impl Scheme {
 		}
 	}
 
-	pub fn primary(&self) -> ARGB {
+	pub fn primary(&self) -> Argb {
 		self.primary
 	}
-	pub fn on_primary(&self) -> ARGB {
+	pub fn on_primary(&self) -> Argb {
 		self.on_primary
 	}
-	pub fn primary_container(&self) -> ARGB {
+	pub fn primary_container(&self) -> Argb {
 		self.primary_container
 	}
-	pub fn on_primary_container(&self) -> ARGB {
+	pub fn on_primary_container(&self) -> Argb {
 		self.on_primary_container
 	}
-	pub fn secondary(&self) -> ARGB {
+	pub fn secondary(&self) -> Argb {
 		self.secondary
 	}
-	pub fn on_secondary(&self) -> ARGB {
+	pub fn on_secondary(&self) -> Argb {
 		self.on_secondary
 	}
-	pub fn secondary_container(&self) -> ARGB {
+	pub fn secondary_container(&self) -> Argb {
 		self.secondary_container
 	}
-	pub fn on_secondary_container(&self) -> ARGB {
+	pub fn on_secondary_container(&self) -> Argb {
 		self.on_secondary_container
 	}
-	pub fn tertiary(&self) -> ARGB {
+	pub fn tertiary(&self) -> Argb {
 		self.tertiary
 	}
-	pub fn on_tertiary(&self) -> ARGB {
+	pub fn on_tertiary(&self) -> Argb {
 		self.on_tertiary
 	}
-	pub fn tertiary_container(&self) -> ARGB {
+	pub fn tertiary_container(&self) -> Argb {
 		self.tertiary_container
 	}
-	pub fn on_tertiary_container(&self) -> ARGB {
+	pub fn on_tertiary_container(&self) -> Argb {
 		self.on_tertiary_container
 	}
-	pub fn error(&self) -> ARGB {
+	pub fn error(&self) -> Argb {
 		self.error
 	}
-	pub fn on_error(&self) -> ARGB {
+	pub fn on_error(&self) -> Argb {
 		self.on_error
 	}
-	pub fn error_container(&self) -> ARGB {
+	pub fn error_container(&self) -> Argb {
 		self.error_container
 	}
-	pub fn on_error_container(&self) -> ARGB {
+	pub fn on_error_container(&self) -> Argb {
 		self.on_error_container
 	}
-	pub fn background(&self) -> ARGB {
+	pub fn background(&self) -> Argb {
 		self.background
 	}
-	pub fn on_background(&self) -> ARGB {
+	pub fn on_background(&self) -> Argb {
 		self.on_background
 	}
-	pub fn surface(&self) -> ARGB {
+	pub fn surface(&self) -> Argb {
 		self.surface
 	}
-	pub fn on_surface(&self) -> ARGB {
+	pub fn on_surface(&self) -> Argb {
 		self.on_surface
 	}
-	pub fn surface_variant(&self) -> ARGB {
+	pub fn surface_variant(&self) -> Argb {
 		self.surface_variant
 	}
-	pub fn on_surface_variant(&self) -> ARGB {
+	pub fn on_surface_variant(&self) -> Argb {
 		self.on_surface_variant
 	}
-	pub fn outline(&self) -> ARGB {
+	pub fn outline(&self) -> Argb {
 		self.outline
 	}
-	pub fn outline_variant(&self) -> ARGB {
+	pub fn outline_variant(&self) -> Argb {
 		self.outline_variant
 	}
-	pub fn shadow(&self) -> ARGB {
+	pub fn shadow(&self) -> Argb {
 		self.shadow
 	}
-	pub fn scrim(&self) -> ARGB {
+	pub fn scrim(&self) -> Argb {
 		self.scrim
 	}
-	pub fn inverse_surface(&self) -> ARGB {
+	pub fn inverse_surface(&self) -> Argb {
 		self.inverse_surface
 	}
-	pub fn inverse_on_surface(&self) -> ARGB {
+	pub fn inverse_on_surface(&self) -> Argb {
 		self.inverse_on_surface
 	}
-	pub fn inverse_primary(&self) -> ARGB {
+	pub fn inverse_primary(&self) -> Argb {
 		self.inverse_primary
 	}
+
+	/// The snake_case name of every role, in declaration order.
+	pub const ROLE_NAMES: [&'static str; 29] = [
+		"primary",
+		"on_primary",
+		"primary_container",
+		"on_primary_container",
+		"secondary",
+		"on_secondary",
+		"secondary_container",
+		"on_secondary_container",
+		"tertiary",
+		"on_tertiary",
+		"tertiary_container",
+		"on_tertiary_container",
+		"error",
+		"on_error",
+		"error_container",
+		"on_error_container",
+		"background",
+		"on_background",
+		"surface",
+		"on_surface",
+		"surface_variant",
+		"on_surface_variant",
+		"outline",
+		"outline_variant",
+		"shadow",
+		"scrim",
+		"inverse_surface",
+		"inverse_on_surface",
+		"inverse_primary",
+	];
+
+	/// Every role with its name from [`Scheme::ROLE_NAMES`].
+	pub fn roles(&self) -> [(&'static str, Argb); 29] {
+		let colors = [
+			self.primary,
+			self.on_primary,
+			self.primary_container,
+			self.on_primary_container,
+			self.secondary,
+			self.on_secondary,
+			self.secondary_container,
+			self.on_secondary_container,
+			self.tertiary,
+			self.on_tertiary,
+			self.tertiary_container,
+			self.on_tertiary_container,
+			self.error,
+			self.on_error,
+			self.error_container,
+			self.on_error_container,
+			self.background,
+			self.on_background,
+			self.surface,
+			self.on_surface,
+			self.surface_variant,
+			self.on_surface_variant,
+			self.outline,
+			self.outline_variant,
+			self.shadow,
+			self.scrim,
+			self.inverse_surface,
+			self.inverse_on_surface,
+			self.inverse_primary,
+		];
+		std::array::from_fn(|i| (Self::ROLE_NAMES[i], colors[i]))
+	}
+
+	/// Builds a scheme by looking up each role by its snake_case name, as
+	/// listed in [`Scheme::ROLE_NAMES`].
+	pub fn from_roles<E>(mut role: impl FnMut(&'static str) -> Result<Argb, E>) -> Result<Self, E> {
+		let mut colors = [Argb::default(); 29];
+		for (color, name) in colors.iter_mut().zip(Self::ROLE_NAMES) {
+			*color = role(name)?;
+		}
+		Ok(Self::new(
+			colors[0], colors[1], colors[2], colors[3], colors[4], colors[5], colors[6], colors[7],
+			colors[8], colors[9], colors[10], colors[11], colors[12], colors[13], colors[14],
+			colors[15], colors[16], colors[17], colors[18], colors[19], colors[20], colors[21],
+			colors[22], colors[23], colors[24], colors[25], colors[26], colors[27], colors[28],
+		))
+	}
 }
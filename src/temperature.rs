@@ -0,0 +1,198 @@
+use crate::{
+	hct::Hct,
+	utils::{color::lab_from_argb, math::sanitize_degrees_double},
+};
+
+//https://github.com/material-foundation/material-color-utilities/blob/main/typescript/temperature/temperature_cache.ts
+
+/// Warm/cool analysis of a color, comparing it to every hue at its chroma and tone.
+///
+/// The colors at each whole hue are computed once, on construction, and
+/// shared by every query.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TemperatureCache {
+	input: Hct,
+	input_temp: f64,
+	hcts_by_hue: Vec<Hct>,
+	temps_by_hue: Vec<f64>,
+	coldest: (Hct, f64),
+	warmest: (Hct, f64),
+}
+
+impl TemperatureCache {
+	pub fn new(input: Hct) -> Self {
+		let hcts_by_hue: Vec<Hct> = (0..=360)
+			.map(|hue| Hct::from(hue as f64, input.chroma(), input.tone()))
+			.collect();
+		let temps_by_hue: Vec<f64> = hcts_by_hue.iter().map(Self::raw_temperature).collect();
+		let input_temp = Self::raw_temperature(&input);
+
+		let mut by_temp: Vec<(&Hct, f64)> = hcts_by_hue
+			.iter()
+			.zip(temps_by_hue.iter().copied())
+			.chain([(&input, input_temp)])
+			.collect();
+		by_temp.sort_by(|a, b| a.1.total_cmp(&b.1));
+		let coldest = (by_temp[0].0.clone(), by_temp[0].1);
+		let warmest = (
+			by_temp[by_temp.len() - 1].0.clone(),
+			by_temp[by_temp.len() - 1].1,
+		);
+
+		Self {
+			input,
+			input_temp,
+			hcts_by_hue,
+			temps_by_hue,
+			coldest,
+			warmest,
+		}
+	}
+
+	pub fn input(&self) -> &Hct {
+		&self.input
+	}
+
+	pub fn coldest(&self) -> Hct {
+		self.coldest.0.clone()
+	}
+
+	pub fn warmest(&self) -> Hct {
+		self.warmest.0.clone()
+	}
+
+	/// Temperature of the input relative to the coldest (0.0) and warmest
+	/// (1.0) colors at its chroma and tone.
+	pub fn input_relative_temperature(&self) -> f64 {
+		self.relative(self.input_temp)
+	}
+
+	pub fn relative_temperature(&self, hct: &Hct) -> f64 {
+		self.relative(Self::raw_temperature(hct))
+	}
+
+	/// The color whose relative temperature is the opposite of the input's,
+	/// found on the arc of hues going through the other end of the
+	/// coldest-warmest range.
+	pub fn complement(&self) -> Hct {
+		let coldest_hue = self.coldest.0.hue();
+		let warmest_hue = self.warmest.0.hue();
+		let start_hue_is_coldest_to_warmest =
+			is_between(self.input.hue(), coldest_hue, warmest_hue);
+		let (start_hue, end_hue) = if start_hue_is_coldest_to_warmest {
+			(warmest_hue, coldest_hue)
+		} else {
+			(coldest_hue, warmest_hue)
+		};
+
+		let complement_relative_temp = 1.0 - self.input_relative_temperature();
+		let mut smallest_error = 1000.0;
+		let mut answer = self.hue_index(self.input.hue());
+
+		for hue_addend in 0..=360 {
+			let hue = sanitize_degrees_double(start_hue + hue_addend as f64);
+			if !is_between(hue, start_hue, end_hue) {
+				continue;
+			}
+
+			let index = self.hue_index(hue);
+			let error = (complement_relative_temp - self.relative(self.temps_by_hue[index])).abs();
+			if error < smallest_error {
+				smallest_error = error;
+				answer = index;
+			}
+		}
+
+		self.hcts_by_hue[answer].clone()
+	}
+
+	/// `count` colors around the input, which sits in the middle, picked from
+	/// the color wheel cut into `divisions` slices of equal temperature change.
+	///
+	/// Upstream uses 5 colors and 12 divisions by default.
+	pub fn analogous(&self, count: usize, divisions: usize) -> Vec<Hct> {
+		if count == 0 {
+			return Vec::new();
+		}
+
+		let start_hue = self.hue_index(self.input.hue());
+		let start_temp = self.relative(self.temps_by_hue[start_hue]);
+
+		let mut absolute_total_temp_delta = 0.0;
+		let mut last_temp = start_temp;
+		for i in 0..360 {
+			let temp = self.relative(self.temps_by_hue[(start_hue + i) % 360]);
+			absolute_total_temp_delta += (temp - last_temp).abs();
+			last_temp = temp;
+		}
+
+		let temp_step = absolute_total_temp_delta / divisions as f64;
+		let mut all_colors = vec![start_hue];
+		let mut total_temp_delta = 0.0;
+		let mut hue_addend = 1;
+		last_temp = start_temp;
+		while all_colors.len() < divisions {
+			let index = (start_hue + hue_addend) % 360;
+			let temp = self.relative(self.temps_by_hue[index]);
+			total_temp_delta += (temp - last_temp).abs();
+
+			let mut index_addend = 0;
+			while all_colors.len() < divisions
+				&& total_temp_delta >= (all_colors.len() + index_addend) as f64 * temp_step
+			{
+				all_colors.push(index);
+				index_addend += 1;
+			}
+
+			last_temp = temp;
+			hue_addend += 1;
+			if hue_addend > 360 {
+				all_colors.resize(divisions, index);
+				break;
+			}
+		}
+
+		let increase_hue_count = (count - 1) / 2;
+		let decrease_hue_count = count - increase_hue_count - 1;
+		let len = all_colors.len();
+
+		let mut answers = Vec::with_capacity(count);
+		for i in (1..=increase_hue_count).rev() {
+			answers.push(self.hcts_by_hue[all_colors[(len - i % len) % len]].clone());
+		}
+		answers.push(self.input.clone());
+		for i in 1..=decrease_hue_count {
+			answers.push(self.hcts_by_hue[all_colors[i % len]].clone());
+		}
+		answers
+	}
+
+	/// Warmth of a color, from about -0.5 for grays and cool blues to about
+	/// 3.0 for saturated oranges, based on its L*a*b* hue and chroma.
+	pub fn raw_temperature(color: &Hct) -> f64 {
+		let [_, a, b] = lab_from_argb(color.to_int());
+		let hue = sanitize_degrees_double(b.atan2(a).to_degrees());
+		let chroma = a.hypot(b);
+		-0.5 + 0.02 * chroma.powf(1.07) * sanitize_degrees_double(hue - 50.0).to_radians().cos()
+	}
+
+	fn relative(&self, temp: f64) -> f64 {
+		let range = self.warmest.1 - self.coldest.1;
+		if range == 0.0 {
+			return 0.5;
+		}
+		(temp - self.coldest.1) / range
+	}
+
+	fn hue_index(&self, hue: f64) -> usize {
+		(hue.round() as usize).min(360)
+	}
+}
+
+fn is_between(angle: f64, a: f64, b: f64) -> bool {
+	if a < b {
+		a <= angle && angle <= b
+	} else {
+		a <= angle || angle <= b
+	}
+}
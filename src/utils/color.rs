@@ -1,10 +1,11 @@
 use crate::utils::math as math_utils;
+use std::fmt;
 
 use super::math::matrix_multiply;
 
 //https://github.com/material-foundation/material-color-utilities/blob/main/typescript/utils/color_utils.ts
 
-const SRGB_TO_XYZ: [[f64; 3]; 3] = [
+pub(crate) const SRGB_TO_XYZ: [[f64; 3]; 3] = [
 	[0.41233895, 0.35762064, 0.18051042],
 	[0.2126, 0.7152, 0.0722],
 	[0.01932141, 0.11916382, 0.95034478],
@@ -27,56 +28,161 @@ const XYZ_TO_SRGB: [[f64; 3]; 3] = [
 const WHITE_POINT_D65: [f64; 3] = [95.047, 100.0, 108.883];
 
 pub type RGB = [u8; 3];
-pub type ARGB = [u8; 4];
 
-pub fn argb_from_rgb(red: u8, green: u8, blue: u8) -> ARGB {
-	[255, red, green, blue]
+#[deprecated(since = "0.2.0", note = "use `Argb`")]
+pub type ARGB = Argb;
+
+/// A color packed as `0xAARRGGBB`.
+///
+/// Converts from and into `u32` in that layout, and from and into
+/// `[alpha, red, green, blue]` byte arrays. Formats as `#rrggbb` when opaque
+/// and `#aarrggbb` otherwise, which is also how it is serialized.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Argb(u32);
+
+impl Argb {
+	pub const fn from_rgba(red: u8, green: u8, blue: u8, alpha: u8) -> Self {
+		Self(u32::from_be_bytes([alpha, red, green, blue]))
+	}
+
+	pub const fn from_rgb(red: u8, green: u8, blue: u8) -> Self {
+		Self::from_rgba(red, green, blue, 255)
+	}
+
+	pub const fn alpha(&self) -> u8 {
+		self.0.to_be_bytes()[0]
+	}
+
+	pub const fn red(&self) -> u8 {
+		self.0.to_be_bytes()[1]
+	}
+
+	pub const fn green(&self) -> u8 {
+		self.0.to_be_bytes()[2]
+	}
+
+	pub const fn blue(&self) -> u8 {
+		self.0.to_be_bytes()[3]
+	}
+
+	pub const fn is_opaque(&self) -> bool {
+		self.alpha() == 255
+	}
+}
+
+impl From<u32> for Argb {
+	fn from(argb: u32) -> Self {
+		Self(argb)
+	}
+}
+
+impl From<Argb> for u32 {
+	fn from(argb: Argb) -> Self {
+		argb.0
+	}
+}
+
+impl From<[u8; 4]> for Argb {
+	fn from(argb: [u8; 4]) -> Self {
+		Self(u32::from_be_bytes(argb))
+	}
 }
 
-pub fn argb_from_linrgb(linrgb: [f64; 3]) -> ARGB {
+impl From<Argb> for [u8; 4] {
+	fn from(argb: Argb) -> Self {
+		argb.0.to_be_bytes()
+	}
+}
+
+impl fmt::Display for Argb {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		if self.is_opaque() {
+			write!(f, "#{:06x}", self.0 & 0x00ff_ffff)
+		} else {
+			write!(f, "#{:08x}", self.0)
+		}
+	}
+}
+
+impl fmt::LowerHex for Argb {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		fmt::LowerHex::fmt(&self.0, f)
+	}
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Argb {
+	fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		serializer.collect_str(self)
+	}
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Argb {
+	fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		let hex = <std::borrow::Cow<str>>::deserialize(deserializer)?;
+		hex.parse().map_err(serde::de::Error::custom)
+	}
+}
+
+impl fmt::UpperHex for Argb {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		fmt::UpperHex::fmt(&self.0, f)
+	}
+}
+
+pub fn argb_from_rgb(red: u8, green: u8, blue: u8) -> Argb {
+	Argb::from_rgb(red, green, blue)
+}
+
+pub fn argb_from_linrgb(linrgb: [f64; 3]) -> Argb {
 	let r = delinearized(linrgb[0]);
 	let g = delinearized(linrgb[1]);
 	let b = delinearized(linrgb[2]);
 	argb_from_rgb(r, g, b)
 }
 
-pub fn alpha_from_argb(argb: ARGB) -> u8 {
-	argb[0]
+pub fn alpha_from_argb(argb: Argb) -> u8 {
+	argb.alpha()
+}
+
+pub fn red_from_argb(argb: Argb) -> u8 {
+	argb.red()
 }
 
-pub fn red_from_argb(argb: ARGB) -> u8 {
-	argb[1]
+pub fn green_from_argb(argb: Argb) -> u8 {
+	argb.green()
 }
 
-pub fn green_from_argb(argb: ARGB) -> u8 {
-	argb[2]
+pub fn blue_from_argb(argb: Argb) -> u8 {
+	argb.blue()
 }
 
-pub fn blue_from_argb(argb: ARGB) -> u8 {
-	argb[3]
+pub fn is_opaque(argb: Argb) -> bool {
+	argb.is_opaque()
 }
 
+#[deprecated(since = "0.2.0", note = "use `alpha_from_argb` with an `Argb`")]
 pub fn alpha_from_argb_numeric(argb: f64) -> u8 {
-	((argb as u32) >> 24 & 255) as u8
+	alpha_from_argb(Argb::from(argb as u32))
 }
 
+#[deprecated(since = "0.2.0", note = "use `red_from_argb` with an `Argb`")]
 pub fn red_from_argb_numeric(argb: f64) -> u8 {
-	((argb as u32) >> 16 & 255) as u8
+	red_from_argb(Argb::from(argb as u32))
 }
 
+#[deprecated(since = "0.2.0", note = "use `green_from_argb` with an `Argb`")]
 pub fn green_from_argb_numeric(argb: f64) -> u8 {
-	((argb as u32) >> 8 & 255) as u8
+	green_from_argb(Argb::from(argb as u32))
 }
 
+#[deprecated(since = "0.2.0", note = "use `blue_from_argb` with an `Argb`")]
 pub fn blue_from_argb_numeric(argb: f64) -> u8 {
-	((argb as u32) & 255) as u8
+	blue_from_argb(Argb::from(argb as u32))
 }
 
-/*pub fn is_opaque(argb: f64) -> bool {
-	alpha_from_argb(argb) >= 255.0
-}*/
-
-pub fn argb_from_xyz(x: f64, y: f64, z: f64) -> ARGB {
+pub fn argb_from_xyz(x: f64, y: f64, z: f64) -> Argb {
 	let [linear_r, linear_g, linear_b] = matrix_multiply([x, y, z], XYZ_TO_SRGB);
 
 	let r = delinearized(linear_r);
@@ -86,15 +192,15 @@ pub fn argb_from_xyz(x: f64, y: f64, z: f64) -> ARGB {
 	argb_from_rgb(r, g, b)
 }
 
-pub fn xyz_from_argb(argb: ARGB) -> [f64; 3] {
-	let r = linearized(argb[1]);
-	let g = linearized(argb[2]);
-	let b = linearized(argb[3]);
+pub fn xyz_from_argb(argb: Argb) -> [f64; 3] {
+	let r = linearized(argb.red());
+	let g = linearized(argb.green());
+	let b = linearized(argb.blue());
 
 	math_utils::matrix_multiply([r, g, b], SRGB_TO_XYZ)
 }
 
-pub fn argb_from_lab(l: f64, a: f64, b: f64) -> ARGB {
+pub fn argb_from_lab(l: f64, a: f64, b: f64) -> Argb {
 	let fy = (l + 16.0) / 116.0;
 	let fx = a / 500.0 + fy;
 	let fz = fy - b / 200.0;
@@ -110,10 +216,10 @@ pub fn argb_from_lab(l: f64, a: f64, b: f64) -> ARGB {
 	argb_from_xyz(x, y, z)
 }
 
-pub fn lab_from_argb(argb: f64) -> [f64; 3] {
-	let linear_r = linearized(red_from_argb_numeric(argb));
-	let linear_g = linearized(green_from_argb_numeric(argb));
-	let linear_b = linearized(blue_from_argb_numeric(argb));
+pub fn lab_from_argb(argb: Argb) -> [f64; 3] {
+	let linear_r = linearized(argb.red());
+	let linear_g = linearized(argb.green());
+	let linear_b = linearized(argb.blue());
 
 	let [x, y, z] = matrix_multiply([linear_r, linear_g, linear_b], SRGB_TO_XYZ);
 
@@ -132,13 +238,13 @@ pub fn lab_from_argb(argb: f64) -> [f64; 3] {
 	[l, a, b]
 }
 
-pub fn argb_from_lstar(lstar: f64) -> ARGB {
+pub fn argb_from_lstar(lstar: f64) -> Argb {
 	let y = y_from_lstar(lstar);
 	let component = delinearized(y);
 	argb_from_rgb(component, component, component)
 }
 
-pub fn lstar_from_argb(argb: ARGB) -> f64 {
+pub fn lstar_from_argb(argb: Argb) -> f64 {
 	let y = xyz_from_argb(argb)[1];
 	116.0 * lab_f(y / 100.0) - 16.0
 }
@@ -147,6 +253,10 @@ pub fn y_from_lstar(lstar: f64) -> f64 {
 	100.0 * lab_inv_f((lstar + 16.0) / 116.0)
 }
 
+pub fn lstar_from_y(y: f64) -> f64 {
+	lab_f(y / 100.0) * 116.0 - 16.0
+}
+
 pub fn linearized(rgb_component: u8) -> f64 {
 	//assert!((0.0..=255.0).contains(&rgb_component));
 
@@ -0,0 +1,73 @@
+use super::color::{lstar_from_y, y_from_lstar};
+
+//https://github.com/material-foundation/material-color-utilities/blob/main/typescript/contrast/contrast.ts
+
+/// Contrast ratio of two tones, from 1.0 (same tone) to 21.0 (black on white).
+pub fn ratio_of_tones(tone_a: f64, tone_b: f64) -> f64 {
+	let tone_a = tone_a.clamp(0.0, 100.0);
+	let tone_b = tone_b.clamp(0.0, 100.0);
+	ratio_of_ys(y_from_lstar(tone_a), y_from_lstar(tone_b))
+}
+
+/// Contrast ratio of two relative luminances, in the 0 to 100 range.
+pub fn ratio_of_ys(y1: f64, y2: f64) -> f64 {
+	let lighter = y1.max(y2);
+	let darker = if lighter == y2 { y1 } else { y2 };
+	(lighter + 5.0) / (darker + 5.0)
+}
+
+/// Tone `>= tone` that reaches `ratio` against it, or `None` if it is out of range.
+pub fn lighter(tone: f64, ratio: f64) -> Option<f64> {
+	if !(0.0..=100.0).contains(&tone) {
+		return None;
+	}
+
+	let dark_y = y_from_lstar(tone);
+	let light_y = ratio * (dark_y + 5.0) - 5.0;
+	let real_contrast = ratio_of_ys(light_y, dark_y);
+	let delta = (real_contrast - ratio).abs();
+	if real_contrast < ratio && delta > 0.04 {
+		return None;
+	}
+
+	// Ensure gamut mapping, which requires a 'range' on tone, will still
+	// result in the correct ratio by darkening slightly.
+	let value = lstar_from_y(light_y) + 0.4;
+	if !(0.0..=100.0).contains(&value) {
+		return None;
+	}
+	Some(value)
+}
+
+/// Tone `<= tone` that reaches `ratio` against it, or `None` if it is out of range.
+pub fn darker(tone: f64, ratio: f64) -> Option<f64> {
+	if !(0.0..=100.0).contains(&tone) {
+		return None;
+	}
+
+	let light_y = y_from_lstar(tone);
+	let dark_y = (light_y + 5.0) / ratio - 5.0;
+	let real_contrast = ratio_of_ys(light_y, dark_y);
+	let delta = (real_contrast - ratio).abs();
+	if real_contrast < ratio && delta > 0.04 {
+		return None;
+	}
+
+	// Ensure gamut mapping, which requires a 'range' on tone, will still
+	// result in the correct ratio by darkening slightly.
+	let value = lstar_from_y(dark_y) - 0.4;
+	if !(0.0..=100.0).contains(&value) {
+		return None;
+	}
+	Some(value)
+}
+
+/// Like [`lighter`], but falls back to white when the ratio can't be reached.
+pub fn lighter_unsafe(tone: f64, ratio: f64) -> f64 {
+	lighter(tone, ratio).unwrap_or(100.0)
+}
+
+/// Like [`darker`], but falls back to black when the ratio can't be reached.
+pub fn darker_unsafe(tone: f64, ratio: f64) -> f64 {
+	darker(tone, ratio).unwrap_or(0.0)
+}
@@ -1,2 +1,4 @@
 pub mod color;
+pub mod contrast;
 pub mod math;
+pub mod string;
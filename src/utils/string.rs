@@ -0,0 +1,372 @@
+use super::color::Argb;
+use std::{error::Error, f64::consts::PI, fmt, str::FromStr};
+
+//https://github.com/material-foundation/material-color-utilities/blob/main/typescript/utils/string_utils.ts
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParseColorError {
+	/// Not 3, 4, 6 or 8 hex digits.
+	InvalidHex,
+	/// A malformed `rgb()`, `rgba()`, `hsl()` or `hsla()` call.
+	InvalidFunction,
+	/// Not one of the named CSS colors.
+	UnknownName,
+}
+
+impl fmt::Display for ParseColorError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(match self {
+			ParseColorError::InvalidHex => "invalid hex color",
+			ParseColorError::InvalidFunction => "invalid css color function",
+			ParseColorError::UnknownName => "unknown css color name",
+		})
+	}
+}
+
+impl Error for ParseColorError {}
+
+/// Hex string of a color, `#rrggbb` when opaque and `#aarrggbb` otherwise.
+pub fn hex_from_argb(argb: Argb) -> String {
+	argb.to_string()
+}
+
+/// CSS hex string of a color, `#rrggbb` when opaque and `#rrggbbaa` otherwise.
+pub fn css_from_argb(argb: Argb) -> String {
+	if argb.is_opaque() {
+		argb.to_string()
+	} else {
+		format!(
+			"#{:02x}{:02x}{:02x}{:02x}",
+			argb.red(),
+			argb.green(),
+			argb.blue(),
+			argb.alpha()
+		)
+	}
+}
+
+/// Parses `rgb`, `argb`, `rrggbb` or `aarrggbb` hex digits, with or without a
+/// leading `#`.
+///
+/// Alpha comes first, as in [`hex_from_argb`]. Use [`argb_from_css`] for
+/// CSS's `#rrggbbaa` order.
+pub fn argb_from_hex(hex: &str) -> Result<Argb, ParseColorError> {
+	let hex = hex.trim();
+	argb_from_hex_digits(&parse_hex_digits(hex.strip_prefix('#').unwrap_or(hex))?)
+}
+
+/// Parses a CSS color: hex (`#rgb`, `#rgba`, `#rrggbb`, `#rrggbbaa`),
+/// `rgb()`, `rgba()`, `hsl()`, `hsla()`, or one of the named colors.
+pub fn argb_from_css(css: &str) -> Result<Argb, ParseColorError> {
+	let css = css.trim().to_ascii_lowercase();
+
+	if let Some(hex) = css.strip_prefix('#') {
+		let digits = parse_hex_digits(hex)?;
+		return Ok(match digits.as_slice() {
+			[r, g, b, a] => Argb::from_rgba(r * 17, g * 17, b * 17, a * 17),
+			[r1, r2, g1, g2, b1, b2, a1, a2] => {
+				Argb::from_rgba(r1 << 4 | r2, g1 << 4 | g2, b1 << 4 | b2, a1 << 4 | a2)
+			}
+			digits => argb_from_hex_digits(digits)?,
+		});
+	}
+
+	if let Some((name, args)) = css.strip_suffix(')').and_then(|css| css.split_once('(')) {
+		let args = split_arguments(args).ok_or(ParseColorError::InvalidFunction)?;
+		return match name.trim() {
+			"rgb" | "rgba" => argb_from_rgb_arguments(&args),
+			"hsl" | "hsla" => argb_from_hsl_arguments(&args),
+			_ => None,
+		}
+		.ok_or(ParseColorError::InvalidFunction);
+	}
+
+	argb_from_name(&css).ok_or(ParseColorError::UnknownName)
+}
+
+/// One of the 148 named CSS colors, or `transparent`. Case-insensitive.
+pub fn argb_from_name(name: &str) -> Option<Argb> {
+	let name = name.trim().to_ascii_lowercase();
+	if name == "transparent" {
+		return Some(Argb::from(0x00000000));
+	}
+	NAMED_COLORS
+		.iter()
+		.find(|(named, _)| *named == name)
+		.map(|(_, rgb)| Argb::from(0xff000000 | rgb))
+}
+
+impl FromStr for Argb {
+	type Err = ParseColorError;
+
+	/// Same as [`argb_from_hex`], so it round-trips with `Display`.
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		argb_from_hex(s)
+	}
+}
+
+/// Values of `hex`, which must only hold hex digits.
+fn parse_hex_digits(hex: &str) -> Result<Vec<u8>, ParseColorError> {
+	hex.chars()
+		.map(|c| c.to_digit(16).map(|d| d as u8))
+		.collect::<Option<Vec<_>>>()
+		.ok_or(ParseColorError::InvalidHex)
+}
+
+/// Color of 3, 4, 6 or 8 hex digit values, alpha first.
+fn argb_from_hex_digits(digits: &[u8]) -> Result<Argb, ParseColorError> {
+	Ok(match digits {
+		[r, g, b] => Argb::from_rgb(r * 17, g * 17, b * 17),
+		[a, r, g, b] => Argb::from_rgba(r * 17, g * 17, b * 17, a * 17),
+		[r1, r2, g1, g2, b1, b2] => Argb::from_rgb(r1 << 4 | r2, g1 << 4 | g2, b1 << 4 | b2),
+		[a1, a2, r1, r2, g1, g2, b1, b2] => {
+			Argb::from_rgba(r1 << 4 | r2, g1 << 4 | g2, b1 << 4 | b2, a1 << 4 | a2)
+		}
+		_ => return Err(ParseColorError::InvalidHex),
+	})
+}
+
+/// Splits the inside of a CSS color function into its components, with the
+/// alpha last. Accepts both `a, b, c, d` and `a b c / d`.
+fn split_arguments(args: &str) -> Option<Vec<&str>> {
+	let args: Vec<&str> = if args.contains(',') {
+		args.split(',').map(str::trim).collect()
+	} else {
+		let (components, alpha) = match args.split_once('/') {
+			Some((components, alpha)) => (components, Some(alpha.trim())),
+			None => (args, None),
+		};
+		components.split_whitespace().chain(alpha).collect()
+	};
+	if (args.len() == 3 || args.len() == 4) && args.iter().all(|arg| !arg.is_empty()) {
+		Some(args)
+	} else {
+		None
+	}
+}
+
+fn parse_number(arg: &str) -> Option<f64> {
+	arg.parse::<f64>().ok().filter(|n| n.is_finite())
+}
+
+/// A number, or a percentage scaled so that `100%` is `scale`.
+fn parse_number_or_percentage(arg: &str, scale: f64) -> Option<f64> {
+	match arg.strip_suffix('%') {
+		Some(percentage) => parse_number(percentage).map(|p| p / 100.0 * scale),
+		None => parse_number(arg),
+	}
+}
+
+fn parse_alpha(args: &[&str]) -> Option<u8> {
+	match args.get(3) {
+		Some(alpha) => {
+			let alpha = parse_number_or_percentage(alpha, 1.0)?;
+			Some((alpha.clamp(0.0, 1.0) * 255.0).round() as u8)
+		}
+		None => Some(255),
+	}
+}
+
+fn parse_hue(arg: &str) -> Option<f64> {
+	let units = [
+		("deg", 1.0),
+		("grad", 0.9),
+		("rad", 180.0 / PI),
+		("turn", 360.0),
+	];
+	for (unit, degrees) in units {
+		if let Some(hue) = arg.strip_suffix(unit) {
+			return parse_number(hue).map(|hue| hue * degrees);
+		}
+	}
+	parse_number(arg)
+}
+
+fn argb_from_rgb_arguments(args: &[&str]) -> Option<Argb> {
+	let channel = |arg: &str| {
+		parse_number_or_percentage(arg, 255.0).map(|c| c.clamp(0.0, 255.0).round() as u8)
+	};
+	Some(Argb::from_rgba(
+		channel(args[0])?,
+		channel(args[1])?,
+		channel(args[2])?,
+		parse_alpha(args)?,
+	))
+}
+
+fn argb_from_hsl_arguments(args: &[&str]) -> Option<Argb> {
+	let hue = parse_hue(args[0])?.rem_euclid(360.0);
+	let saturation = (parse_number_or_percentage(args[1], 100.0)? / 100.0).clamp(0.0, 1.0);
+	let lightness = (parse_number_or_percentage(args[2], 100.0)? / 100.0).clamp(0.0, 1.0);
+
+	let chroma = (1.0 - (2.0 * lightness - 1.0).abs()) * saturation;
+	let x = chroma * (1.0 - ((hue / 60.0) % 2.0 - 1.0).abs());
+	let (r, g, b) = match (hue / 60.0) as u8 {
+		0 => (chroma, x, 0.0),
+		1 => (x, chroma, 0.0),
+		2 => (0.0, chroma, x),
+		3 => (0.0, x, chroma),
+		4 => (x, 0.0, chroma),
+		_ => (chroma, 0.0, x),
+	};
+	let m = lightness - chroma / 2.0;
+	let channel = |c: f64| ((c + m) * 255.0).clamp(0.0, 255.0).round() as u8;
+
+	Some(Argb::from_rgba(
+		channel(r),
+		channel(g),
+		channel(b),
+		parse_alpha(args)?,
+	))
+}
+
+const NAMED_COLORS: [(&str, u32); 148] = [
+	("aliceblue", 0xf0f8ff),
+	("antiquewhite", 0xfaebd7),
+	("aqua", 0x00ffff),
+	("aquamarine", 0x7fffd4),
+	("azure", 0xf0ffff),
+	("beige", 0xf5f5dc),
+	("bisque", 0xffe4c4),
+	("black", 0x000000),
+	("blanchedalmond", 0xffebcd),
+	("blue", 0x0000ff),
+	("blueviolet", 0x8a2be2),
+	("brown", 0xa52a2a),
+	("burlywood", 0xdeb887),
+	("cadetblue", 0x5f9ea0),
+	("chartreuse", 0x7fff00),
+	("chocolate", 0xd2691e),
+	("coral", 0xff7f50),
+	("cornflowerblue", 0x6495ed),
+	("cornsilk", 0xfff8dc),
+	("crimson", 0xdc143c),
+	("cyan", 0x00ffff),
+	("darkblue", 0x00008b),
+	("darkcyan", 0x008b8b),
+	("darkgoldenrod", 0xb8860b),
+	("darkgray", 0xa9a9a9),
+	("darkgreen", 0x006400),
+	("darkgrey", 0xa9a9a9),
+	("darkkhaki", 0xbdb76b),
+	("darkmagenta", 0x8b008b),
+	("darkolivegreen", 0x556b2f),
+	("darkorange", 0xff8c00),
+	("darkorchid", 0x9932cc),
+	("darkred", 0x8b0000),
+	("darksalmon", 0xe9967a),
+	("darkseagreen", 0x8fbc8f),
+	("darkslateblue", 0x483d8b),
+	("darkslategray", 0x2f4f4f),
+	("darkslategrey", 0x2f4f4f),
+	("darkturquoise", 0x00ced1),
+	("darkviolet", 0x9400d3),
+	("deeppink", 0xff1493),
+	("deepskyblue", 0x00bfff),
+	("dimgray", 0x696969),
+	("dimgrey", 0x696969),
+	("dodgerblue", 0x1e90ff),
+	("firebrick", 0xb22222),
+	("floralwhite", 0xfffaf0),
+	("forestgreen", 0x228b22),
+	("fuchsia", 0xff00ff),
+	("gainsboro", 0xdcdcdc),
+	("ghostwhite", 0xf8f8ff),
+	("gold", 0xffd700),
+	("goldenrod", 0xdaa520),
+	("gray", 0x808080),
+	("green", 0x008000),
+	("greenyellow", 0xadff2f),
+	("grey", 0x808080),
+	("honeydew", 0xf0fff0),
+	("hotpink", 0xff69b4),
+	("indianred", 0xcd5c5c),
+	("indigo", 0x4b0082),
+	("ivory", 0xfffff0),
+	("khaki", 0xf0e68c),
+	("lavender", 0xe6e6fa),
+	("lavenderblush", 0xfff0f5),
+	("lawngreen", 0x7cfc00),
+	("lemonchiffon", 0xfffacd),
+	("lightblue", 0xadd8e6),
+	("lightcoral", 0xf08080),
+	("lightcyan", 0xe0ffff),
+	("lightgoldenrodyellow", 0xfafad2),
+	("lightgray", 0xd3d3d3),
+	("lightgreen", 0x90ee90),
+	("lightgrey", 0xd3d3d3),
+	("lightpink", 0xffb6c1),
+	("lightsalmon", 0xffa07a),
+	("lightseagreen", 0x20b2aa),
+	("lightskyblue", 0x87cefa),
+	("lightslategray", 0x778899),
+	("lightslategrey", 0x778899),
+	("lightsteelblue", 0xb0c4de),
+	("lightyellow", 0xffffe0),
+	("lime", 0x00ff00),
+	("limegreen", 0x32cd32),
+	("linen", 0xfaf0e6),
+	("magenta", 0xff00ff),
+	("maroon", 0x800000),
+	("mediumaquamarine", 0x66cdaa),
+	("mediumblue", 0x0000cd),
+	("mediumorchid", 0xba55d3),
+	("mediumpurple", 0x9370db),
+	("mediumseagreen", 0x3cb371),
+	("mediumslateblue", 0x7b68ee),
+	("mediumspringgreen", 0x00fa9a),
+	("mediumturquoise", 0x48d1cc),
+	("mediumvioletred", 0xc71585),
+	("midnightblue", 0x191970),
+	("mintcream", 0xf5fffa),
+	("mistyrose", 0xffe4e1),
+	("moccasin", 0xffe4b5),
+	("navajowhite", 0xffdead),
+	("navy", 0x000080),
+	("oldlace", 0xfdf5e6),
+	("olive", 0x808000),
+	("olivedrab", 0x6b8e23),
+	("orange", 0xffa500),
+	("orangered", 0xff4500),
+	("orchid", 0xda70d6),
+	("palegoldenrod", 0xeee8aa),
+	("palegreen", 0x98fb98),
+	("paleturquoise", 0xafeeee),
+	("palevioletred", 0xdb7093),
+	("papayawhip", 0xffefd5),
+	("peachpuff", 0xffdab9),
+	("peru", 0xcd853f),
+	("pink", 0xffc0cb),
+	("plum", 0xdda0dd),
+	("powderblue", 0xb0e0e6),
+	("purple", 0x800080),
+	("rebeccapurple", 0x663399),
+	("red", 0xff0000),
+	("rosybrown", 0xbc8f8f),
+	("royalblue", 0x4169e1),
+	("saddlebrown", 0x8b4513),
+	("salmon", 0xfa8072),
+	("sandybrown", 0xf4a460),
+	("seagreen", 0x2e8b57),
+	("seashell", 0xfff5ee),
+	("sienna", 0xa0522d),
+	("silver", 0xc0c0c0),
+	("skyblue", 0x87ceeb),
+	("slateblue", 0x6a5acd),
+	("slategray", 0x708090),
+	("slategrey", 0x708090),
+	("snow", 0xfffafa),
+	("springgreen", 0x00ff7f),
+	("steelblue", 0x4682b4),
+	("tan", 0xd2b48c),
+	("teal", 0x008080),
+	("thistle", 0xd8bfd8),
+	("tomato", 0xff6347),
+	("turquoise", 0x40e0d0),
+	("violet", 0xee82ee),
+	("wheat", 0xf5deb3),
+	("white", 0xffffff),
+	("whitesmoke", 0xf5f5f5),
+	("yellow", 0xffff00),
+	("yellowgreen", 0x9acd32),
+];
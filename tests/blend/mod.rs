@@ -0,0 +1,25 @@
+use super::consts::*;
+use material_rs_color_utilities::{blend::harmonize, hct::Hct, utils::color::Argb};
+
+#[test]
+fn harmonize_red() {
+	assert_eq!(harmonize(RED, BLUE), Argb::from(0xfffb0057));
+	assert_eq!(harmonize(RED, GREEN), Argb::from(0xffd85600));
+}
+
+#[test]
+fn harmonize_blue() {
+	assert_eq!(harmonize(BLUE, GREEN), Argb::from(0xff0047a3));
+	assert_eq!(harmonize(BLUE, RED), Argb::from(0xff5700dc));
+}
+
+#[test]
+fn harmonize_green() {
+	assert_eq!(harmonize(GREEN, BLUE), Argb::from(0xff00fc94));
+	assert_eq!(harmonize(GREEN, RED), Argb::from(0xffb1f000));
+}
+
+#[test]
+fn harmonize_same_hue() {
+	assert_eq!(harmonize(RED, RED), Hct::from_argb(RED).to_int());
+}
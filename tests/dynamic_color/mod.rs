@@ -0,0 +1,247 @@
+use super::consts::*;
+use assert_approx_eq::assert_approx_eq;
+use material_rs_color_utilities::{
+	dynamic_color::{
+		ContrastCurve, DynamicColor, DynamicScheme, MaterialDynamicColors, Platform, SpecVersion,
+		ToneDeltaPair, TonePolarity,
+	},
+	palettes::CorePalette,
+	scheme::Scheme,
+	utils::contrast::ratio_of_tones,
+};
+
+mod contrast_curve {
+	use super::*;
+
+	#[test]
+	fn interpolates_between_levels() {
+		let curve = ContrastCurve::new(3.0, 4.5, 7.0, 11.0);
+
+		assert_approx_eq!(curve.get(-2.0), 3.0);
+		assert_approx_eq!(curve.get(-1.0), 3.0);
+		assert_approx_eq!(curve.get(-0.5), 3.75);
+		assert_approx_eq!(curve.get(0.0), 4.5);
+		assert_approx_eq!(curve.get(0.25), 5.75);
+		assert_approx_eq!(curve.get(0.5), 7.0);
+		assert_approx_eq!(curve.get(0.75), 9.0);
+		assert_approx_eq!(curve.get(1.0), 11.0);
+	}
+
+	#[test]
+	fn on_surface_follows_its_curve() {
+		for (is_dark, contrast_level, ratio) in [
+			(false, 0.0, 7.0),
+			(false, 0.5, 11.0),
+			(true, 0.0, 7.0),
+			(true, 0.5, 11.0),
+		] {
+			let scheme = DynamicScheme::new(CorePalette::of(BLUE), is_dark, contrast_level);
+			let on_surface = MaterialDynamicColors::on_surface().get_tone(&scheme);
+			let surface = MaterialDynamicColors::surface().get_tone(&scheme);
+
+			assert!(ratio_of_tones(on_surface, surface) >= ratio);
+		}
+
+		// 21:1 can't be reached over a tone 10 surface, white is the closest.
+		let scheme = DynamicScheme::new(CorePalette::of(BLUE), true, 1.0);
+		assert_eq!(MaterialDynamicColors::on_surface().get_tone(&scheme), 100.0);
+	}
+
+	#[test]
+	fn reduced_contrast_moves_towards_background() {
+		let standard = DynamicScheme::new(CorePalette::of(BLUE), false, 0.0);
+		let reduced = DynamicScheme::new(CorePalette::of(BLUE), false, -1.0);
+		let outline = MaterialDynamicColors::outline();
+		let surface = MaterialDynamicColors::surface().get_tone(&standard);
+
+		let reduced_ratio = ratio_of_tones(outline.get_tone(&reduced), surface);
+		assert!(reduced_ratio < ratio_of_tones(outline.get_tone(&standard), surface));
+		assert!(reduced_ratio >= 1.5);
+	}
+}
+
+mod tone_delta_pair {
+	use super::*;
+
+	fn role_a() -> DynamicColor {
+		DynamicColor::new(
+			"role_a",
+			CorePalette::a1,
+			|_| 62.0,
+			true,
+			Some(|_| MaterialDynamicColors::surface()),
+			None,
+			Some(|_| pair()),
+		)
+	}
+
+	fn role_b() -> DynamicColor {
+		DynamicColor::new(
+			"role_b",
+			CorePalette::a1,
+			|_| 64.0,
+			true,
+			Some(|_| MaterialDynamicColors::surface()),
+			None,
+			Some(|_| pair()),
+		)
+	}
+
+	fn pair() -> ToneDeltaPair {
+		ToneDeltaPair::new(role_a(), role_b(), 10.0, TonePolarity::Nearer, false)
+	}
+
+	#[test]
+	fn keeps_roles_apart_in_light_scheme() {
+		let scheme = DynamicScheme::new(CorePalette::of(BLUE), false, 0.0);
+
+		assert_eq!(role_a().get_tone(&scheme), 62.0);
+		assert_eq!(role_b().get_tone(&scheme), 49.0);
+	}
+
+	#[test]
+	fn keeps_roles_apart_in_dark_scheme() {
+		let scheme = DynamicScheme::new(CorePalette::of(BLUE), true, 0.0);
+
+		assert_eq!(role_a().get_tone(&scheme), 62.0);
+		assert_eq!(role_b().get_tone(&scheme), 72.0);
+	}
+
+	#[test]
+	fn containers_stay_apart_from_their_roles() {
+		for argb in [RED, GREEN, BLUE, WHITE, BLACK] {
+			for is_dark in [false, true] {
+				for contrast_level in [-1.0, 0.0, 0.5, 1.0] {
+					let scheme = DynamicScheme::new(CorePalette::of(argb), is_dark, contrast_level);
+
+					let pairs = [
+						(
+							MaterialDynamicColors::primary(),
+							MaterialDynamicColors::primary_container(),
+						),
+						(
+							MaterialDynamicColors::secondary(),
+							MaterialDynamicColors::secondary_container(),
+						),
+						(
+							MaterialDynamicColors::tertiary(),
+							MaterialDynamicColors::tertiary_container(),
+						),
+						(
+							MaterialDynamicColors::error(),
+							MaterialDynamicColors::error_container(),
+						),
+					];
+					for (role, container) in pairs {
+						let delta = role.get_tone(&scheme) - container.get_tone(&scheme);
+						assert!(delta.abs() >= 10.0, "{} collapsed", role.name());
+					}
+				}
+			}
+		}
+	}
+}
+
+mod spec_version {
+	use super::*;
+
+	#[test]
+	fn spec_2021_matches_scheme() {
+		for is_dark in [false, true] {
+			let mut scheme = DynamicScheme::new(CorePalette::of(BLUE), is_dark, 0.0)
+				.with_spec_version(SpecVersion::Spec2021);
+			let expected = if is_dark {
+				Scheme::dark(BLUE)
+			} else {
+				Scheme::light(BLUE)
+			};
+
+			assert_eq!(Scheme::from_dynamic_scheme(&mut scheme), expected);
+		}
+	}
+
+	#[test]
+	fn spec_2025_tones() {
+		let light = DynamicScheme::new(CorePalette::of(BLUE), false, 0.0)
+			.with_spec_version(SpecVersion::Spec2025);
+		let dark = DynamicScheme::new(CorePalette::of(BLUE), true, 0.0)
+			.with_spec_version(SpecVersion::Spec2025);
+
+		assert_eq!(MaterialDynamicColors::surface().get_tone(&light), 98.0);
+		assert_eq!(MaterialDynamicColors::surface().get_tone(&dark), 4.0);
+		assert_eq!(
+			MaterialDynamicColors::on_primary_container().get_tone(&light),
+			30.0
+		);
+		assert_eq!(
+			MaterialDynamicColors::on_error_container().get_tone(&dark),
+			90.0
+		);
+		assert_eq!(
+			MaterialDynamicColors::surface_container_high().get_tone(&dark),
+			12.0
+		);
+		assert_eq!(
+			MaterialDynamicColors::surface_variant().get_tone(&dark),
+			MaterialDynamicColors::surface_container_highest().get_tone(&dark)
+		);
+	}
+
+	#[test]
+	fn scheme_entry_points() {
+		let mut dark = DynamicScheme::new(CorePalette::of(BLUE), true, 0.0)
+			.with_spec_version(SpecVersion::Spec2025);
+
+		assert_eq!(
+			Scheme::light_with_spec_version(BLUE, SpecVersion::Spec2021),
+			Scheme::light(BLUE)
+		);
+		assert_eq!(
+			Scheme::dark_with_spec_version(BLUE, SpecVersion::Spec2025),
+			Scheme::from_dynamic_scheme(&mut dark)
+		);
+		assert_ne!(
+			Scheme::dark_with_spec_version(BLUE, SpecVersion::Spec2025).surface(),
+			Scheme::dark(BLUE).surface()
+		);
+	}
+}
+
+mod platform {
+	use super::*;
+
+	#[test]
+	fn watch_is_dark_only() {
+		let light_watch =
+			DynamicScheme::new(CorePalette::of(BLUE), false, 0.0).with_platform(Platform::Watch);
+		let dark_watch =
+			DynamicScheme::new(CorePalette::of(BLUE), true, 0.0).with_platform(Platform::Watch);
+
+		assert!(light_watch.is_dark());
+		assert_eq!(
+			Scheme::from_dynamic_scheme(&mut light_watch.clone()),
+			Scheme::from_dynamic_scheme(&mut dark_watch.clone())
+		);
+	}
+
+	#[test]
+	fn watch_surfaces_are_black() {
+		let mut watch =
+			DynamicScheme::new(CorePalette::of(BLUE), true, 0.0).with_platform(Platform::Watch);
+		let phone = DynamicScheme::new(CorePalette::of(BLUE), true, 0.0);
+
+		assert_eq!(MaterialDynamicColors::surface().get_argb(&mut watch), BLACK);
+		assert_eq!(
+			MaterialDynamicColors::background().get_argb(&mut watch),
+			BLACK
+		);
+		assert_eq!(
+			MaterialDynamicColors::primary().get_tone(&watch),
+			MaterialDynamicColors::primary().get_tone(&phone)
+		);
+		assert!(
+			MaterialDynamicColors::surface_container().get_tone(&watch)
+				> MaterialDynamicColors::surface().get_tone(&watch)
+		);
+	}
+}
@@ -0,0 +1,1012 @@
+use super::consts::*;
+use material_rs_color_utilities::{palettes::CorePalette, scheme::Scheme, utils::color::Argb};
+
+/// `base` with the roles in `overrides` replaced.
+fn with_roles(base: &Scheme, overrides: &[(&str, Argb)]) -> Scheme {
+	let roles = base.roles();
+	Scheme::from_roles(|role| {
+		let (_, argb) = overrides
+			.iter()
+			.chain(&roles)
+			.find(|(name, _)| *name == role)
+			.unwrap();
+		Ok::<_, ()>(*argb)
+	})
+	.unwrap()
+}
+
+mod css {
+	use super::*;
+	use material_rs_color_utilities::export::CssExporter;
+
+	#[test]
+	fn light_on_root_and_dark_in_media_query() {
+		let css = CssExporter::new(&Scheme::light(BLUE), &Scheme::dark(BLUE)).export();
+		let (light, dark) = css
+			.split_once("@media (prefers-color-scheme: dark)")
+			.unwrap();
+
+		assert!(light.starts_with(":root {\n"));
+		assert!(light.contains("  --md-sys-color-primary: #343dff;\n"));
+		assert!(light.contains("  --md-sys-color-on-primary-container: #00006e;\n"));
+		assert!(dark.contains("    --md-sys-color-primary: #bec2ff;\n"));
+		assert_eq!(light.matches("--md-sys-color-").count(), 29);
+		assert_eq!(dark.matches("--md-sys-color-").count(), 29);
+		assert!(!css.contains("--md-ref-palette"));
+		assert!(!css.contains("data-theme"));
+	}
+
+	#[test]
+	fn palette_tokens() {
+		let mut core = CorePalette::of(BLUE);
+		let css = CssExporter::new(&Scheme::light(BLUE), &Scheme::dark(BLUE))
+			.with_palette(&core)
+			.export();
+
+		assert_eq!(css.matches("--md-ref-palette-").count(), 6 * 13);
+		assert!(css.contains(&format!(
+			"  --md-ref-palette-primary40: {};\n",
+			core.a1().tone(40)
+		)));
+		assert!(css.contains(&format!(
+			"  --md-ref-palette-neutral-variant99: {};\n",
+			core.n2().tone(99)
+		)));
+		assert!(css.contains("  --md-ref-palette-error100: #ffffff;\n"));
+	}
+
+	#[test]
+	fn data_theme_selectors() {
+		let css = CssExporter::new(&Scheme::light(BLUE), &Scheme::dark(BLUE))
+			.with_data_theme(true)
+			.export();
+		let (_, light) = css.split_once("[data-theme=\"light\"] {\n").unwrap();
+		let (light, dark) = light.split_once("[data-theme=\"dark\"] {\n").unwrap();
+
+		assert!(light.contains("  --md-sys-color-primary: #343dff;\n"));
+		assert!(dark.contains("  --md-sys-color-primary: #bec2ff;\n"));
+		assert!(css.ends_with("}\n"));
+	}
+}
+
+mod android {
+	use super::*;
+	use material_rs_color_utilities::export::AndroidExporter;
+
+	fn exporter() -> AndroidExporter {
+		AndroidExporter::new(&Scheme::light(BLUE), &Scheme::dark(BLUE))
+	}
+
+	#[test]
+	fn colors() {
+		let light = exporter().colors(false);
+		let dark = exporter().colors(true);
+
+		assert!(light.starts_with("<?xml version=\"1.0\" encoding=\"utf-8\"?>\n<resources>\n"));
+		assert!(light.contains("    <color name=\"md_theme_primary\">#343dff</color>\n"));
+		assert!(dark.contains("    <color name=\"md_theme_primary\">#bec2ff</color>\n"));
+		assert_eq!(light.matches("<color name=\"md_theme_").count(), 29);
+		assert_eq!(dark.matches("<color name=\"md_theme_").count(), 29);
+	}
+
+	#[test]
+	fn theme() {
+		let theme = exporter().with_theme_name("Theme.Brand").theme();
+
+		assert!(theme.contains(
+			"    <style name=\"Theme.Brand\" parent=\"Theme.Material3.DayNight.NoActionBar\">\n"
+		));
+		assert!(
+			theme.contains("        <item name=\"colorPrimary\">@color/md_theme_primary</item>\n")
+		);
+		assert!(theme.contains(
+			"        <item name=\"android:colorBackground\">@color/md_theme_background</item>\n"
+		));
+		assert!(theme.contains(
+			"        <item name=\"colorSurfaceInverse\">@color/md_theme_inverse_surface</item>\n"
+		));
+		assert_eq!(theme.matches("<item ").count(), 27);
+	}
+
+	#[test]
+	fn files() {
+		let paths = exporter().files().map(|(path, _)| path);
+
+		assert_eq!(
+			paths,
+			[
+				"values/colors.xml",
+				"values-night/colors.xml",
+				"values/themes.xml"
+			]
+		);
+	}
+}
+
+mod compose {
+	use super::*;
+	use material_rs_color_utilities::{dynamic_color::DynamicScheme, export::ComposeExporter};
+
+	#[test]
+	fn color_kt() {
+		let kt = ComposeExporter::new(&Scheme::light(BLUE), &Scheme::dark(BLUE))
+			.with_package("com.brand.theme")
+			.color_kt();
+
+		assert!(kt
+			.starts_with("package com.brand.theme\n\nimport androidx.compose.ui.graphics.Color\n"));
+		assert!(kt.contains("val primaryLight = Color(0xFF343DFF)\n"));
+		assert!(kt.contains("val onPrimaryContainerLight = Color(0xFF00006E)\n"));
+		assert!(kt.contains("val primaryDark = Color(0xFFBEC2FF)\n"));
+		assert!(!kt.contains("shadow"));
+		assert!(!kt.contains("surfaceContainer"));
+	}
+
+	#[test]
+	fn theme_kt() {
+		let kt = ComposeExporter::new(&Scheme::light(BLUE), &Scheme::dark(BLUE))
+			.with_theme_name("BrandTheme")
+			.theme_kt();
+
+		assert!(kt.contains("\nprivate val lightScheme = lightColorScheme(\n"));
+		assert!(kt.contains("\nprivate val darkScheme = darkColorScheme(\n"));
+		assert!(kt.contains("    inverseOnSurface = inverseOnSurfaceDark,\n"));
+		assert!(kt.contains("fun BrandTheme(\n"));
+		assert!(kt.contains("colorScheme = if (darkTheme) darkScheme else lightScheme,"));
+		assert_eq!(kt.matches(" = primaryLight,").count(), 1);
+	}
+
+	#[test]
+	fn surface_containers_from_dynamic_schemes() {
+		let exporter = ComposeExporter::from_dynamic_schemes(
+			&mut DynamicScheme::new(CorePalette::of(BLUE), false, 0.0),
+			&mut DynamicScheme::new(CorePalette::of(BLUE), true, 0.0),
+		);
+		let color_kt = exporter.color_kt();
+		let theme_kt = exporter.theme_kt();
+
+		assert!(color_kt.contains("val primaryLight = Color(0xFF343DFF)\n"));
+		assert!(color_kt.contains("val surfaceContainerHighestDark = Color(0x"));
+		assert!(theme_kt.contains("    surfaceDim = surfaceDimLight,\n"));
+		assert_eq!(theme_kt.matches("    surfaceContainer").count(), 10);
+	}
+}
+
+mod flutter {
+	use super::*;
+	use material_rs_color_utilities::export::FlutterExporter;
+
+	#[test]
+	fn color_schemes() {
+		let dart = FlutterExporter::new(&Scheme::light(BLUE), &Scheme::dark(BLUE)).export();
+
+		assert!(dart.starts_with("import 'package:flutter/material.dart';\n"));
+		assert!(dart.contains(
+			"\nconst lightColorScheme = ColorScheme(\n  brightness: Brightness.light,\n  primary: Color(0xFF343DFF),\n"
+		));
+		assert!(dart.contains(
+			"\nconst darkColorScheme = ColorScheme(\n  brightness: Brightness.dark,\n  primary: Color(0xFFBEC2FF),\n"
+		));
+		assert!(dart.contains("  onInverseSurface: Color(0x"));
+		assert!(!dart.contains("inverseOnSurface"));
+		assert_eq!(dart.matches("  onPrimaryContainer: ").count(), 2);
+		assert!(!dart.contains("Palette"));
+	}
+
+	#[test]
+	fn palettes() {
+		let mut core = CorePalette::of(BLUE);
+		let dart = FlutterExporter::new(&Scheme::light(BLUE), &Scheme::dark(BLUE))
+			.with_palette(&core)
+			.export();
+
+		assert!(dart.contains("\nconst primaryPalette = <int, Color>{\n  0: Color(0xFF000000),\n"));
+		assert!(dart.contains(&format!("  40: Color(0x{:08X}),\n", core.a1().tone(40))));
+		assert!(dart.contains("\nconst neutralVariantPalette = <int, Color>{\n"));
+		assert_eq!(dart.matches("Palette = <int, Color>{").count(), 6);
+	}
+}
+
+mod gtk {
+	use super::*;
+	use material_rs_color_utilities::export::GtkExporter;
+
+	#[test]
+	fn named_colors() {
+		let css = GtkExporter::new(&Scheme::light(BLUE)).export();
+
+		assert!(css.starts_with("@define-color accent_color #343dff;\n"));
+		assert!(css.contains("@define-color accent_bg_color #343dff;\n"));
+		assert!(css.contains("@define-color accent_fg_color #ffffff;\n"));
+		assert!(css.contains(&format!(
+			"@define-color window_bg_color {};\n",
+			Scheme::light(BLUE).background()
+		)));
+		assert_eq!(css.lines().count(), 25);
+	}
+
+	#[test]
+	fn dark() {
+		let css = GtkExporter::new(&Scheme::dark(BLUE)).export();
+
+		assert!(css.contains("@define-color accent_bg_color #bec2ff;\n"));
+		assert!(css.contains(&format!(
+			"@define-color view_fg_color {};\n",
+			Scheme::dark(BLUE).on_surface()
+		)));
+	}
+
+	#[test]
+	fn translucent_colors() {
+		let scheme = with_roles(&Scheme::light(BLUE), &[("primary", Argb::from(0x80343dff))]);
+		let css = GtkExporter::new(&scheme).export();
+
+		assert!(css.contains("@define-color accent_bg_color rgba(52, 61, 255, 0.502);\n"));
+		assert!(css.contains("@define-color accent_fg_color #ffffff;\n"));
+	}
+}
+
+mod preview {
+	use super::*;
+	use material_rs_color_utilities::export::{PalettePreview, Preview};
+
+	fn preview() -> Preview {
+		Preview::new(&Scheme::light(BLUE), &CorePalette::of(BLUE)).with_title("Brand <light>")
+	}
+
+	#[test]
+	fn contrast_ratios() {
+		let ratios = preview().contrast_ratios();
+
+		assert_eq!(ratios.len(), 12);
+		assert_eq!((ratios[0].0, ratios[0].1), ("primary", "on_primary"));
+		assert!(ratios.iter().any(
+			|(role, on_role, _)| (*role, *on_role) == ("inverse_surface", "inverse_on_surface")
+		));
+		for (role, _, ratio) in ratios {
+			assert!(ratio >= 4.5, "{role}: {ratio}");
+		}
+	}
+
+	#[test]
+	fn svg() {
+		let svg = preview().svg();
+		let ratio = preview().contrast_ratios()[0].2;
+
+		assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\" "));
+		assert!(svg.ends_with("</svg>\n"));
+		assert!(svg.contains(">Brand &lt;light&gt;</text>"));
+		assert!(svg.contains("fill=\"#343dff\"/>"));
+		assert!(svg.contains(&format!(">on_primary {ratio:.1}:1</text>")));
+		assert!(svg.contains(">neutral-variant</text>"));
+		assert_eq!(svg.matches("<rect ").count(), 1 + 12 + 6 * 13);
+	}
+
+	#[test]
+	fn translucent_colors() {
+		let scheme = with_roles(&Scheme::light(BLUE), &[("primary", Argb::from(0x80343dff))]);
+		let preview = Preview::new(&scheme, &CorePalette::of(BLUE));
+
+		assert!(preview.svg().contains(" rx=\"8\" fill=\"#343dff80\"/>"));
+		assert!(preview
+			.html()
+			.contains("style=\"background: #343dff80; color: #ffffff;\""));
+		assert!(!preview.html().contains("#80343dff"));
+	}
+
+	#[test]
+	fn html() {
+		let html = preview().html();
+
+		assert!(html.starts_with("<!DOCTYPE html>\n"));
+		assert!(html.contains("<title>Brand &lt;light&gt;</title>"));
+		assert!(html.contains(
+			"<div class=\"swatch\" style=\"background: #343dff; color: #ffffff;\">primary<span>#343dff</span>"
+		));
+		assert_eq!(html.matches("<tr>").count(), 6);
+		assert_eq!(html.matches("<td ").count(), 6 * 13);
+	}
+
+	#[test]
+	fn ansi_lines() {
+		let ansi = preview().ansi();
+		let lines: Vec<_> = ansi.lines().collect();
+		let ratio = preview().contrast_ratios()[0].2;
+
+		assert_eq!(lines.len(), 12 + 5 + 6);
+		assert!(lines[0].starts_with("\x1b[48;2;52;61;255m\x1b[38;2;255;255;255m primary "));
+		assert!(lines[0].contains(&format!(" #343dff  on_primary {ratio:.1}:1 ")));
+		assert!(lines.iter().all(|line| line.ends_with("\x1b[0m")));
+		assert!(lines[12..17].iter().all(|line| !line.contains(":1")));
+		assert!(lines[12..17].iter().any(|line| line.contains(" outline ")));
+		assert!(!ansi.contains(" on_primary  "));
+		assert!(lines[21].starts_with("neutral-variant "));
+		assert_eq!(lines[21].matches("\x1b[48;2;").count(), 13);
+	}
+
+	#[test]
+	fn palette_ansi_strip() {
+		let mut palette = CorePalette::of(BLUE).a1().clone();
+		let ansi = PalettePreview::new(&palette).ansi();
+		let primary = palette.tone(40);
+
+		assert!(ansi.starts_with("\x1b[48;2;0;0;0m\x1b[38;2;255;255;255m   0 "));
+		assert!(ansi.contains(&format!(
+			"\x1b[48;2;{};{};{}m\x1b[38;2;255;255;255m  40 ",
+			primary.red(),
+			primary.green(),
+			primary.blue()
+		)));
+		assert!(ansi.ends_with(" 100 \x1b[0m\n"));
+		assert_eq!(ansi.matches("\x1b[48;2;").count(), 13);
+	}
+}
+
+mod qt {
+	use super::*;
+	use material_rs_color_utilities::export::QtExporter;
+
+	#[test]
+	fn palette() {
+		let exporter = QtExporter::new(&Scheme::light(BLUE));
+		let cpp = exporter.palette_cpp();
+
+		assert!(exporter
+			.palette()
+			.contains(&("Highlight", Scheme::light(BLUE).primary())));
+		assert!(cpp.starts_with("QPalette palette;\n"));
+		assert!(
+			cpp.contains("palette.setColor(QPalette::Highlight, QColor::fromRgba(0xff343dff));\n")
+		);
+		assert!(cpp.contains(
+			"palette.setColor(QPalette::HighlightedText, QColor::fromRgba(0xffffffff));\n"
+		));
+		assert!(!cpp.contains("QPalette::Accent"));
+		assert_eq!(cpp.matches("palette.setColor(").count(), 15);
+	}
+
+	#[test]
+	fn style_sheet() {
+		let scheme = Scheme::dark(BLUE);
+		let qss = QtExporter::new(&scheme).qss();
+
+		assert!(qss.starts_with(&format!(
+			"QWidget {{\n    background-color: {};\n    color: {};\n",
+			scheme.background(),
+			scheme.on_background()
+		)));
+		assert!(qss.contains("QPushButton {\n    background-color: #bec2ff;\n"));
+		assert!(qss.contains(&format!(
+			"QToolTip {{\n    background-color: {};\n",
+			scheme.inverse_surface()
+		)));
+		assert_eq!(qss.matches('{').count(), qss.matches('}').count());
+	}
+}
+
+mod xcassets {
+	use super::*;
+	use material_rs_color_utilities::export::XcassetsExporter;
+	use serde_json::{json, Value};
+
+	fn exporter() -> XcassetsExporter {
+		XcassetsExporter::new(&Scheme::light(BLUE), &Scheme::dark(BLUE))
+	}
+
+	#[test]
+	fn one_color_set_per_role() {
+		let files = exporter().with_catalog_name("Brand").files();
+
+		assert_eq!(files.len(), 30);
+		assert_eq!(files[0].0, "Brand.xcassets/Contents.json");
+		assert_eq!(files[1].0, "Brand.xcassets/primary.colorset/Contents.json");
+		assert!(files
+			.iter()
+			.any(|(path, _)| path == "Brand.xcassets/onPrimaryContainer.colorset/Contents.json"));
+		for (_, contents) in &files {
+			serde_json::from_str::<Value>(contents).unwrap();
+		}
+	}
+
+	#[test]
+	fn light_and_dark_appearances() {
+		let files = exporter().files();
+		let primary: Value = serde_json::from_str(&files[1].1).unwrap();
+		let colors = primary["colors"].as_array().unwrap();
+
+		assert_eq!(colors.len(), 2);
+		assert!(colors[0].get("appearances").is_none());
+		assert_eq!(
+			colors[0]["color"]["components"],
+			json!({ "alpha": "1.000", "red": "0x34", "green": "0x3D", "blue": "0xFF" })
+		);
+		assert_eq!(
+			colors[1]["appearances"],
+			json!([{ "appearance": "luminosity", "value": "dark" }])
+		);
+		assert_eq!(colors[1]["color"]["components"]["red"], json!("0xBE"));
+	}
+
+	#[test]
+	fn write_to() {
+		let directory = std::env::temp_dir().join(format!("xcassets-{}", std::process::id()));
+		exporter().write_to(&directory).unwrap();
+
+		assert!(directory.join("Colors.xcassets/Contents.json").is_file());
+		assert!(directory
+			.join("Colors.xcassets/inversePrimary.colorset/Contents.json")
+			.is_file());
+		std::fs::remove_dir_all(directory).unwrap();
+	}
+}
+
+#[cfg(feature = "json")]
+mod dtcg {
+	use super::*;
+	use material_rs_color_utilities::export::{
+		dtcg::scheme_from_tokens, DtcgExporter, ImportError,
+	};
+	use serde_json::{json, Value};
+
+	fn tokens() -> String {
+		DtcgExporter::new(
+			&Scheme::light(BLUE),
+			&Scheme::dark(BLUE),
+			&CorePalette::of(BLUE),
+		)
+		.export()
+	}
+
+	#[test]
+	fn palette_tokens() {
+		let mut core = CorePalette::of(BLUE);
+		let document: Value = serde_json::from_str(&tokens()).unwrap();
+		let palettes = &document["md"]["ref"]["palette"];
+
+		assert_eq!(palettes.as_object().unwrap().len(), 6);
+		assert_eq!(
+			palettes["primary"]["40"],
+			json!({ "$type": "color", "$value": core.a1().tone(40).to_string() })
+		);
+		assert_eq!(palettes["neutral-variant"].as_object().unwrap().len(), 13);
+	}
+
+	#[test]
+	fn roles_reference_palettes() {
+		let document: Value = serde_json::from_str(&tokens()).unwrap();
+		let colors = &document["md"]["sys"]["color"];
+
+		assert_eq!(
+			colors["light"]["primary"],
+			json!({ "$type": "color", "$value": "{md.ref.palette.primary.40}" })
+		);
+		assert_eq!(
+			colors["dark"]["on-primary-container"]["$value"],
+			json!("{md.ref.palette.primary.90}")
+		);
+		assert_eq!(
+			colors["light"]["outline"]["$value"],
+			json!("{md.ref.palette.neutral-variant.50}")
+		);
+		assert_eq!(colors["dark"].as_object().unwrap().len(), 29);
+	}
+
+	#[test]
+	fn keys_keep_palette_and_role_order() {
+		let tokens = tokens();
+		let position = |key: &str| tokens.find(key).unwrap();
+
+		assert!(position("\"ref\"") < position("\"sys\""));
+		assert!(position("\"primary\"") < position("\"secondary\""));
+		assert!(position("\"20\"") < position("\"100\""));
+		assert!(position("\"light\"") < position("\"dark\""));
+		assert!(position("\"on-primary\"") < position("\"background\""));
+		assert!(position("\"$type\"") < position("\"$value\""));
+	}
+
+	#[test]
+	fn roundtrip() {
+		let tokens = tokens();
+
+		assert_eq!(
+			scheme_from_tokens(&tokens, false).unwrap(),
+			Scheme::light(BLUE)
+		);
+		assert_eq!(
+			scheme_from_tokens(&tokens, true).unwrap(),
+			Scheme::dark(BLUE)
+		);
+	}
+
+	#[test]
+	fn literal_and_object_values() {
+		let mut document: Value = serde_json::from_str(&tokens()).unwrap();
+		let light = &mut document["md"]["sys"]["color"]["light"];
+		light["primary"]["$value"] = json!("rgb(255, 0, 0)");
+		light["secondary"]["$value"] =
+			json!({ "colorSpace": "srgb", "components": [0, 1, 0], "hex": "#00ff00" });
+		let scheme = scheme_from_tokens(&document.to_string(), false).unwrap();
+
+		assert_eq!(scheme.primary(), RED);
+		assert_eq!(scheme.secondary(), GREEN);
+	}
+
+	#[test]
+	fn errors() {
+		let mut document: Value = serde_json::from_str(&tokens()).unwrap();
+		document["md"]["sys"]["color"]["light"]["scrim"]["$value"] =
+			json!("{md.ref.palette.nope.0}");
+		let error = scheme_from_tokens(&document.to_string(), false).unwrap_err();
+		assert!(matches!(error, ImportError::Missing(path) if path == "md.ref.palette.nope.0"));
+
+		document["md"]["sys"]["color"]["light"]["scrim"]["$value"] =
+			json!("{md.sys.color.light.scrim}");
+		let error = scheme_from_tokens(&document.to_string(), false).unwrap_err();
+		assert!(matches!(error, ImportError::InvalidColor(_)));
+
+		assert!(matches!(
+			scheme_from_tokens("{", false),
+			Err(ImportError::Json(_))
+		));
+	}
+}
+
+#[cfg(feature = "json")]
+mod theme_builder {
+	use super::*;
+	use material_rs_color_utilities::{
+		blend::harmonize,
+		export::{ExtendedColor, ImportError, MaterialTheme},
+		palettes::TonalPalette,
+	};
+	use serde_json::{json, Value};
+
+	#[test]
+	fn export_format() {
+		let theme = MaterialTheme::new(BLUE)
+			.with_extended_color(ExtendedColor::new("Success", GREEN, true));
+		let document: Value = serde_json::from_str(&theme.to_json()).unwrap();
+
+		assert_eq!(document["seed"], json!("#0000ff"));
+		assert_eq!(document["coreColors"], json!({ "primary": "#0000ff" }));
+		assert_eq!(
+			document["extendedColors"],
+			json!([{ "name": "Success", "color": "#00ff00", "description": "", "harmonized": true }])
+		);
+		assert_eq!(document["schemes"].as_object().unwrap().len(), 6);
+		assert_eq!(document["schemes"]["light"]["primary"], json!("#343dff"));
+		assert_eq!(
+			document["schemes"]["light"]["surfaceTint"],
+			json!("#343dff")
+		);
+		assert_eq!(document["schemes"]["dark"]["primary"], json!("#bec2ff"));
+		assert!(document["schemes"]["dark-high-contrast"]["surfaceContainerHighest"].is_string());
+		assert_eq!(
+			document["schemes"]["light"].as_object().unwrap().len(),
+			29 + 7 + 1 + 4
+		);
+		assert_eq!(
+			document["palettes"]["neutral-variant"]["0"],
+			json!("#000000")
+		);
+	}
+
+	#[test]
+	fn schemes() {
+		let theme = MaterialTheme::new(BLUE);
+
+		assert_eq!(theme.scheme("light").unwrap(), Scheme::light(BLUE));
+		assert_eq!(theme.scheme("dark").unwrap(), Scheme::dark(BLUE));
+		assert_eq!(
+			theme.scheme("light-high-contrast").unwrap(),
+			Scheme::light_high_contrast(BLUE)
+		);
+		assert_eq!(theme.scheme_names().count(), 6);
+		assert!(matches!(
+			theme.scheme("sepia"),
+			Err(ImportError::Missing(_))
+		));
+	}
+
+	#[test]
+	fn roundtrip() {
+		let theme = MaterialTheme::custom(BLUE, RED, GREEN, BLACK, WHITE, RED)
+			.with_extended_color(ExtendedColor::new("Brand", Argb::from(0xff6750a4), false));
+
+		assert_eq!(MaterialTheme::from_json(&theme.to_json()).unwrap(), theme);
+	}
+
+	#[test]
+	fn custom_core_palette() {
+		let theme = MaterialTheme::custom(BLUE, RED, GREEN, BLACK, WHITE, RED);
+
+		assert_eq!(
+			theme.core_palette(),
+			CorePalette::custom(BLUE, RED, GREEN, BLACK, WHITE, RED)
+		);
+	}
+
+	#[test]
+	fn core_palette_keeps_generated_palettes() {
+		let mut only_error = MaterialTheme::from_json(
+			r##"{ "seed": "#0000ff", "coreColors": { "primary": "#0000ff", "error": "#ff0000" }, "schemes": {} }"##,
+		)
+		.unwrap()
+		.core_palette();
+		let mut default = CorePalette::of(BLUE);
+
+		assert_eq!(only_error.a1(), &TonalPalette::from_argb(BLUE));
+		assert_eq!(only_error.a2(), default.a2());
+		assert_eq!(only_error.n1(), default.n1());
+		assert_eq!(only_error.error(), &TonalPalette::from_argb(RED));
+	}
+
+	#[test]
+	fn extended_color_roles() {
+		let theme = MaterialTheme::new(BLUE)
+			.with_extended_color(ExtendedColor::new("Brand accent", RED, true))
+			.with_extended_color(ExtendedColor::new("Success", GREEN, false));
+		let document: Value = serde_json::from_str(&theme.to_json()).unwrap();
+		let harmonized = harmonize(RED, BLUE);
+
+		assert_eq!(theme.extended_colors()[0].value(BLUE), harmonized);
+		assert_eq!(theme.extended_colors()[1].value(BLUE), GREEN);
+		assert_eq!(
+			document["schemes"]["light"]["brandAccent"],
+			json!(CorePalette::of(harmonized).a1().tone(40).to_string())
+		);
+		assert_eq!(
+			document["schemes"]["dark"]["onSuccessContainer"],
+			json!(CorePalette::of(GREEN).a1().tone(90).to_string())
+		);
+		assert!(document["schemes"]["light-high-contrast"]["onBrandAccentContainer"].is_string());
+	}
+
+	#[test]
+	fn import() {
+		let json = r##"{
+			"description": "TYPE: CUSTOM\nMaterial Theme Builder export",
+			"seed": "#6750A4",
+			"coreColors": { "primary": "#6750A4", "tertiary": "#7D5260" },
+			"extendedColors": [],
+			"schemes": {
+				"light": {
+					"primary": "#6750A4", "surfaceTint": "#6750A4", "onPrimary": "#FFFFFF",
+					"primaryContainer": "#EADDFF", "onPrimaryContainer": "#21005D",
+					"secondary": "#625B71", "onSecondary": "#FFFFFF",
+					"secondaryContainer": "#E8DEF8", "onSecondaryContainer": "#1D192B",
+					"tertiary": "#7D5260", "onTertiary": "#FFFFFF",
+					"tertiaryContainer": "#FFD8E4", "onTertiaryContainer": "#31111D",
+					"error": "#B3261E", "onError": "#FFFFFF",
+					"errorContainer": "#F9DEDC", "onErrorContainer": "#410E0B",
+					"background": "#FFFBFE", "onBackground": "#1C1B1F",
+					"surface": "#FFFBFE", "onSurface": "#1C1B1F",
+					"surfaceVariant": "#E7E0EC", "onSurfaceVariant": "#49454F",
+					"outline": "#79747E", "outlineVariant": "#CAC4D0",
+					"shadow": "#000000", "scrim": "#000000",
+					"inverseSurface": "#313033", "inverseOnSurface": "#F4EFF4",
+					"inversePrimary": "#D0BCFF", "surfaceDim": "#DED8E1"
+				}
+			},
+			"palettes": { "primary": { "40": "#6750A4" } }
+		}"##;
+		let theme = MaterialTheme::from_json(json).unwrap();
+		let light = theme.scheme("light").unwrap();
+
+		assert_eq!(theme.seed(), Argb::from(0xff6750a4));
+		assert_eq!(light.primary(), Argb::from(0xff6750a4));
+		assert_eq!(light.inverse_on_surface(), Argb::from(0xfff4eff4));
+		assert!(theme.extended_colors().is_empty());
+
+		let mut expected = CorePalette::of(Argb::from(0xff6750a4));
+		*expected.a1() = TonalPalette::from_argb(Argb::from(0xff6750a4));
+		*expected.a3() = TonalPalette::from_argb(Argb::from(0xff7d5260));
+		assert_eq!(theme.core_palette(), expected);
+
+		let document: Value = serde_json::from_str(&theme.to_json()).unwrap();
+		assert_eq!(document["schemes"]["light"]["surfaceDim"], json!("#ded8e1"));
+	}
+
+	#[test]
+	fn import_errors() {
+		assert!(matches!(
+			MaterialTheme::from_json("[]"),
+			Err(ImportError::Json(_))
+		));
+		assert!(matches!(
+			MaterialTheme::from_json(
+				r##"{ "seed": "#zzz", "coreColors": { "primary": "#fff" }, "schemes": {} }"##
+			),
+			Err(ImportError::Json(_))
+		));
+
+		let theme = MaterialTheme::from_json(
+			r##"{ "seed": "#fff", "coreColors": { "primary": "#fff" }, "schemes": { "light": { "primary": "#fff" } } }"##,
+		)
+		.unwrap();
+		assert!(matches!(
+			theme.scheme("light"),
+			Err(ImportError::Missing(path)) if path == "schemes.light.onPrimary"
+		));
+	}
+}
+
+mod tailwind {
+	use super::*;
+	use material_rs_color_utilities::export::TailwindExporter;
+
+	#[test]
+	fn config() {
+		let mut core = CorePalette::of(BLUE);
+		let js = TailwindExporter::new(&core).config();
+
+		assert!(js.starts_with("/** @type {import('tailwindcss').Config} */\nmodule.exports = {\n"));
+		assert!(js.contains("        'primary-0': '#000000',\n"));
+		assert!(js.contains(&format!(
+			"        'primary-40': '{}',\n",
+			core.a1().tone(40)
+		)));
+		assert!(js.contains(&format!(
+			"        'neutral-variant-95': '{}',\n",
+			core.n2().tone(95)
+		)));
+		assert_eq!(js.matches("': '").count(), 6 * 13);
+		assert!(js.ends_with("      },\n    },\n  },\n};\n"));
+	}
+
+	#[test]
+	fn scheme_aliases() {
+		let js = TailwindExporter::new(&CorePalette::of(BLUE))
+			.with_scheme(&Scheme::light(BLUE))
+			.config();
+
+		assert!(js.contains("        'primary': '#343dff',\n"));
+		assert!(js.contains("        'on-primary-container': '#00006e',\n"));
+		assert_eq!(js.matches("': '").count(), 6 * 13 + 29);
+	}
+
+	#[test]
+	fn theme_css_with_css_variables() {
+		let css = TailwindExporter::new(&CorePalette::of(BLUE))
+			.with_css_variables()
+			.theme_css();
+
+		assert!(css.starts_with("@theme {\n  --color-primary-0: #000000;\n"));
+		assert!(css.contains("  --color-error-100: #ffffff;\n"));
+		assert!(css.contains("  --color-on-surface: var(--md-sys-color-on-surface);\n"));
+		assert_eq!(css.matches("var(--md-sys-color-").count(), 29);
+	}
+}
+
+mod terminal {
+	use super::*;
+	use assert_approx_eq::assert_approx_eq;
+	use material_rs_color_utilities::{
+		blend::harmonize,
+		export::TerminalTheme,
+		hct::Hct,
+		utils::{color::lstar_from_argb, contrast::ratio_of_tones},
+	};
+
+	const PURPLE: Argb = Argb::from_rgb(0x67, 0x50, 0xa4);
+
+	fn dark() -> TerminalTheme {
+		TerminalTheme::new(&Scheme::dark(PURPLE), PURPLE)
+	}
+
+	#[test]
+	fn ansi_colors_are_harmonized() {
+		let ansi = dark().ansi();
+		let bases = [
+			RED,
+			GREEN,
+			Argb::from(0xffffff00),
+			BLUE,
+			Argb::from(0xffff00ff),
+		];
+
+		for (i, base) in bases.into_iter().enumerate() {
+			let hue = Hct::from_argb(ansi[i + 1]).hue();
+			let harmonized = Hct::from_argb(harmonize(base, PURPLE)).hue();
+			assert_approx_eq!(hue, harmonized, 3.0);
+			// At most 15° of rotation, plus some solver drift.
+			assert!((hue - Hct::from_argb(base).hue()).abs() <= 17.0);
+		}
+	}
+
+	#[test]
+	fn ansi_tones_follow_the_background() {
+		let dark = dark().ansi();
+		let light = TerminalTheme::new(&Scheme::light(PURPLE), PURPLE).ansi();
+
+		assert_approx_eq!(Hct::from_argb(dark[1]).tone(), 70.0, 1.0);
+		assert_approx_eq!(Hct::from_argb(dark[9]).tone(), 80.0, 1.0);
+		assert_approx_eq!(Hct::from_argb(light[1]).tone(), 40.0, 1.0);
+		assert_approx_eq!(Hct::from_argb(light[15]).tone(), 95.0, 1.0);
+		assert_approx_eq!(Hct::from_argb(dark[0]).tone(), 10.0, 1.0);
+		assert!(Hct::from_argb(dark[0]).tone() < Hct::from_argb(dark[8]).tone());
+	}
+
+	#[test]
+	fn ansi_colors_are_distinct() {
+		for scheme in [Scheme::light(PURPLE), Scheme::dark(PURPLE)] {
+			let ansi = TerminalTheme::new(&scheme, PURPLE).ansi();
+
+			for i in 0..16 {
+				for j in i + 1..16 {
+					assert_ne!(ansi[i], ansi[j], "color{i} and color{j}");
+				}
+			}
+		}
+	}
+
+	#[test]
+	fn ansi_colors_contrast_with_background() {
+		for seed in [PURPLE, RED, GREEN, BLUE, Argb::from(0xffffde3f)] {
+			for scheme in [Scheme::light(seed), Scheme::dark(seed)] {
+				let background = lstar_from_argb(scheme.surface());
+
+				for (i, argb) in TerminalTheme::new(&scheme, seed)
+					.ansi()
+					.into_iter()
+					.enumerate()
+					.filter(|(i, _)| ![0, 7, 8, 15].contains(i))
+				{
+					let ratio = ratio_of_tones(lstar_from_argb(argb), background);
+					assert!(ratio >= 3.0, "color{i} on {seed}: {ratio}");
+				}
+			}
+		}
+	}
+
+	#[test]
+	fn neutrals_follow_the_source() {
+		let ansi = dark().ansi();
+		let mut neutral = CorePalette::of(PURPLE).n1().clone();
+
+		assert_eq!(ansi[7], neutral.tone(80));
+		assert_eq!(ansi[15], neutral.tone(95));
+	}
+
+	#[test]
+	fn alacritty() {
+		let theme = dark();
+		let toml = theme.alacritty();
+
+		assert!(toml.starts_with(&format!(
+			"[colors.primary]\nbackground = \"{}\"\nforeground = \"{}\"\n",
+			theme.background(),
+			theme.foreground()
+		)));
+		assert!(toml.contains(&format!(
+			"\n[colors.normal]\nblack = \"{}\"\n",
+			theme.ansi()[0]
+		)));
+		assert!(toml.contains(&format!(
+			"\n[colors.bright]\nblack = \"{}\"\n",
+			theme.ansi()[8]
+		)));
+		assert!(toml.contains(&format!("magenta = \"{}\"\n", theme.ansi()[13])));
+	}
+
+	#[test]
+	fn kitty() {
+		let theme = dark();
+		let conf = theme.kitty();
+
+		assert!(conf.starts_with(&format!(
+			"foreground {}\n",
+			Scheme::dark(PURPLE).on_surface()
+		)));
+		assert!(conf.contains(&format!("\ncolor0 {}\n", theme.ansi()[0])));
+		assert!(conf.ends_with(&format!("\ncolor15 {}\n", theme.ansi()[15])));
+	}
+
+	#[test]
+	fn wezterm() {
+		let theme = dark().with_name("Brand \"Dark\"");
+		let toml = theme.wezterm();
+
+		assert!(toml.contains(&format!("\nansi = [\"{}\", ", theme.ansi()[0])));
+		assert!(toml.contains(&format!(", \"{}\"]\n\n[metadata]", theme.ansi()[15])));
+		assert!(toml.ends_with("name = \"Brand \\\"Dark\\\"\"\n"));
+	}
+
+	#[test]
+	fn windows_terminal() {
+		let theme = dark().with_name("Brand");
+		let json: serde_json::Value = serde_json::from_str(&theme.windows_terminal()).unwrap();
+
+		assert_eq!(json["name"], "Brand");
+		assert_eq!(json["purple"], theme.ansi()[5].to_string());
+		assert_eq!(json["brightWhite"], theme.ansi()[15].to_string());
+		assert_eq!(json.as_object().unwrap().len(), 5 + 16);
+	}
+}
+
+mod vscode {
+	use super::*;
+	use material_rs_color_utilities::{
+		export::VsCodeTheme,
+		utils::{color::lstar_from_argb, contrast::ratio_of_tones},
+	};
+	use serde_json::Value;
+
+	#[test]
+	fn workbench_colors() {
+		let scheme = Scheme::dark(BLUE);
+		let theme = VsCodeTheme::new(&scheme, &CorePalette::of(BLUE)).with_name("Brand Dark");
+		let json: Value = serde_json::from_str(&theme.export()).unwrap();
+
+		assert_eq!(json["name"], "Brand Dark");
+		assert_eq!(json["type"], "dark");
+		assert_eq!(
+			json["colors"]["editor.background"],
+			scheme.surface().to_string()
+		);
+		assert_eq!(
+			json["colors"]["editor.selectionBackground"],
+			scheme.primary_container().to_string()
+		);
+		assert_eq!(
+			json["colors"]["editorCursor.foreground"],
+			scheme.primary().to_string()
+		);
+	}
+
+	#[test]
+	fn token_colors() {
+		let theme = VsCodeTheme::new(&Scheme::light(BLUE), &CorePalette::of(BLUE));
+		let json: Value = serde_json::from_str(&theme.export()).unwrap();
+		let tokens = json["tokenColors"].as_array().unwrap();
+
+		assert_eq!(json["type"], "light");
+		assert_eq!(tokens.len(), theme.token_colors().len());
+		assert_eq!(tokens[0]["scope"][0], "comment");
+		assert_eq!(tokens[0]["settings"]["fontStyle"], "italic");
+		assert!(tokens[1]["settings"].get("fontStyle").is_none());
+		assert_eq!(
+			tokens[1]["settings"]["foreground"],
+			CorePalette::of(BLUE).a1().tone(40).to_string()
+		);
+	}
+
+	#[test]
+	fn tokens_contrast_with_background() {
+		for seed in [
+			BLUE,
+			RED,
+			GREEN,
+			Argb::from(0xff6750a4),
+			Argb::from(0xffffde3f),
+		] {
+			for is_dark in [false, true] {
+				let scheme = if is_dark {
+					Scheme::dark(seed)
+				} else {
+					Scheme::light(seed)
+				};
+				let theme = VsCodeTheme::new(&scheme, &CorePalette::of(seed));
+				let background = lstar_from_argb(scheme.surface());
+
+				for (scopes, argb, _) in theme.token_colors() {
+					let ratio = ratio_of_tones(lstar_from_argb(argb), background);
+					assert!(ratio >= 4.5, "{scopes:?} on {seed}: {ratio}");
+				}
+			}
+		}
+	}
+
+	#[test]
+	fn mid_tone_surface() {
+		let scheme = with_roles(&Scheme::dark(BLUE), &[("surface", Argb::from(0xff777777))]);
+		let theme = VsCodeTheme::new(&scheme, &CorePalette::of(BLUE));
+		let background = lstar_from_argb(scheme.surface());
+
+		assert!(!theme.is_dark());
+		for (scopes, argb, _) in theme.token_colors() {
+			let ratio = ratio_of_tones(lstar_from_argb(argb), background);
+			assert!(ratio >= 4.5, "{scopes:?}: {ratio}");
+		}
+	}
+}
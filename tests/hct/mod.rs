@@ -1,7 +1,7 @@
 use assert_approx_eq::assert_approx_eq;
-use color_utilities::{
-	hct::{cam16::Cam16, viewing_conditions::ViewingConditions, Hct},
-	utils::color::{self, ARGB},
+use material_rs_color_utilities::{
+	hct::{cam16::Cam16, hct_solver, viewing_conditions::ViewingConditions, Hct},
+	utils::color::{self, Argb},
 };
 
 use super::consts::*;
@@ -162,9 +162,122 @@ fn viewing_conditions() {
 	assert_approx_eq!(vc.z(), 1.909, 0.001);
 }
 
+mod custom_viewing_conditions {
+	use super::*;
+
+	fn dark_room() -> ViewingConditions {
+		ViewingConditions::builder()
+			.adapting_luminance(10.0)
+			.background_lstar(10.0)
+			.surround(0.0)
+			.build()
+	}
+
+	#[test]
+	fn builder_defaults() {
+		assert_eq!(
+			ViewingConditions::builder().build(),
+			ViewingConditions::default()
+		);
+	}
+
+	#[test]
+	fn builder() {
+		let vc = dark_room();
+
+		assert_approx_eq!(vc.c(), 0.525, 0.001);
+		assert_approx_eq!(vc.nc(), 0.8, 0.001);
+		assert!(vc.n() < ViewingConditions::default().n());
+		assert!(vc.fl() < ViewingConditions::default().fl());
+	}
+
+	#[test]
+	fn cam_roundtrip() {
+		let vc = dark_room();
+
+		for argb in [RED, GREEN, BLUE, WHITE, BLACK] {
+			let cam = Cam16::from_int_in_viewing_conditions(argb, &vc);
+			assert_eq!(cam.viewed(&vc), argb);
+
+			let jch = Cam16::from_jch_in_viewing_conditions(cam.j(), cam.chroma(), cam.hue(), &vc);
+			assert_eq!(jch.viewed(&vc), argb);
+		}
+	}
+
+	#[test]
+	fn solver_in_default_viewing_conditions() {
+		let vc = ViewingConditions::default();
+
+		for hue in (15..360).step_by(30) {
+			for chroma in (0..=100).step_by(10) {
+				for tone in (20..=80).step_by(10) {
+					let (hue, chroma, tone) = (hue as f64, chroma as f64, tone as f64);
+					assert_eq!(
+						hct_solver::solve_to_int_in(hue, chroma, tone, &vc),
+						hct_solver::solve_to_int(hue, chroma, tone)
+					);
+				}
+			}
+		}
+	}
+
+	#[test]
+	fn solver_in_dark_room() {
+		let vc = dark_room();
+
+		for hue in (15..360).step_by(30) {
+			for chroma in (20..=40).step_by(10) {
+				for tone in (30..=70).step_by(10) {
+					let (hue, chroma, tone) = (hue as f64, chroma as f64, tone as f64);
+					let argb = hct_solver::solve_to_int_in(hue, chroma, tone, &vc);
+					let cam = hct_solver::solve_to_cam_in(hue, chroma, tone, &vc);
+
+					assert_approx_eq!(color::lstar_from_argb(argb), tone, 0.5);
+					assert_approx_eq!(cam.hue(), hue, 4.0);
+					if ![argb.red(), argb.green(), argb.blue()]
+						.iter()
+						.any(|c| *c == 0 || *c == 255)
+					{
+						assert!(cam.chroma() <= chroma + 2.5);
+					}
+				}
+			}
+		}
+	}
+
+	#[test]
+	fn hct_in_default_viewing_conditions() {
+		for argb in [RED, GREEN, BLUE, WHITE, BLACK] {
+			let hct = Hct::from_argb(argb);
+			let same = hct.in_viewing_conditions(&ViewingConditions::default());
+
+			assert_eq!(same.to_int(), argb);
+		}
+	}
+
+	#[test]
+	fn hct_in_dark_room() {
+		let hct = Hct::from(270.0, 30.0, 50.0);
+		let in_dark_room = hct.in_viewing_conditions(&dark_room());
+
+		assert_approx_eq!(in_dark_room.hue(), 270.0, 5.0);
+		assert!((in_dark_room.tone() - hct.tone()).abs() > 1.0);
+	}
+
+	#[test]
+	fn dark_surround_changes_appearance() {
+		let purple = Argb::from(0xff6750a4);
+		let default = Cam16::from_argb(purple);
+		let dark = Cam16::from_int_in_viewing_conditions(purple, &dark_room());
+
+		assert!((default.j() - dark.j()).abs() > 1.0);
+		assert_ne!(dark.viewed(&ViewingConditions::default()), purple);
+	}
+}
+
 #[test]
 fn cam_solver() {
-	let color_is_on_boundary = |argb: ARGB| -> bool {
+	let color_is_on_boundary = |argb: Argb| -> bool {
 		color::red_from_argb(argb) == 0
 			|| color::red_from_argb(argb) == 255
 			|| color::green_from_argb(argb) == 0
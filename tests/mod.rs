@@ -1,13 +1,20 @@
+mod blend;
+mod dynamic_color;
+mod export;
 mod hct;
 mod palettes;
 mod scheme;
+#[cfg(feature = "serde")]
+mod serialization;
+mod temperature;
+mod utils;
 
 mod consts {
-	use color_utilities::utils::color::ARGB;
+	use material_rs_color_utilities::utils::color::Argb;
 
-	pub const RED: ARGB = [0xFF, 0xFF, 0x00, 0x00];
-	pub const GREEN: ARGB = [0xFF, 0x00, 0xFF, 0x00];
-	pub const BLUE: ARGB = [0xFF, 0x00, 0x00, 0xFF];
-	pub const WHITE: ARGB = [0xFF, 0xFF, 0xFF, 0xFF];
-	pub const BLACK: ARGB = [0xFF, 0x00, 0x00, 0x00];
+	pub const RED: Argb = Argb::from_rgb(0xFF, 0x00, 0x00);
+	pub const GREEN: Argb = Argb::from_rgb(0x00, 0xFF, 0x00);
+	pub const BLUE: Argb = Argb::from_rgb(0x00, 0x00, 0xFF);
+	pub const WHITE: Argb = Argb::from_rgb(0xFF, 0xFF, 0xFF);
+	pub const BLACK: Argb = Argb::from_rgb(0x00, 0x00, 0x00);
 }
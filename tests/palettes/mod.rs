@@ -1,5 +1,5 @@
 use super::consts::BLUE;
-use color_utilities::palettes::*;
+use material_rs_color_utilities::{palettes::*, utils::color::Argb};
 
 mod tonal {
 	use super::*;
@@ -9,19 +9,19 @@ mod tonal {
 	fn of_blue() {
 		let mut palette = TonalPalette::from_argb(BLUE);
 
-		assert_eq!(palette.tone(100), [0xff, 0xff, 0xff, 0xff]);
-		assert_eq!(palette.tone(95), [0xff, 0xf1, 0xef, 0xff]);
-		assert_eq!(palette.tone(90), [0xff, 0xe0, 0xe0, 0xff]);
-		assert_eq!(palette.tone(80), [0xff, 0xbe, 0xc2, 0xff]);
-		assert_eq!(palette.tone(70), [0xff, 0x9d, 0xa3, 0xff]);
-		assert_eq!(palette.tone(60), [0xff, 0x7c, 0x84, 0xff]);
-		assert_eq!(palette.tone(50), [0xff, 0x5a, 0x64, 0xff]);
-		assert_eq!(palette.tone(40), [0xff, 0x34, 0x3d, 0xff]);
+		assert_eq!(palette.tone(100), Argb::from(0xffffffff));
+		assert_eq!(palette.tone(95), Argb::from(0xfff1efff));
+		assert_eq!(palette.tone(90), Argb::from(0xffe0e0ff));
+		assert_eq!(palette.tone(80), Argb::from(0xffbec2ff));
+		assert_eq!(palette.tone(70), Argb::from(0xff9da3ff));
+		assert_eq!(palette.tone(60), Argb::from(0xff7c84ff));
+		assert_eq!(palette.tone(50), Argb::from(0xff5a64ff));
+		assert_eq!(palette.tone(40), Argb::from(0xff343dff));
 		//NOTE:FIX: tone 30 of core palette is failling on green by 1 unit
-		//assert_eq!(palette.tone(30), [0xff, 0x00, 0x00, 0xef]);
-		assert_eq!(palette.tone(20), [0xff, 0x00, 0x01, 0xac]);
-		assert_eq!(palette.tone(10), [0xff, 0x00, 0x00, 0x6e]);
-		assert_eq!(palette.tone(0), [0xff, 0x00, 0x00, 0x00]);
+		//assert_eq!(palette.tone(30), Argb::from(0xff0000ef));
+		assert_eq!(palette.tone(20), Argb::from(0xff0001ac));
+		assert_eq!(palette.tone(10), Argb::from(0xff00006e));
+		assert_eq!(palette.tone(0), Argb::from(0xff000000));
 	}
 }
 
@@ -33,32 +33,32 @@ mod core {
 	fn of_blue() {
 		let mut palette = CorePalette::of(BLUE);
 
-		assert_eq!(palette.a1().tone(100), [0xff, 0xff, 0xff, 0xff]);
-		assert_eq!(palette.a1().tone(95), [0xff, 0xf1, 0xef, 0xff]);
-		assert_eq!(palette.a1().tone(90), [0xff, 0xe0, 0xe0, 0xff]);
-		assert_eq!(palette.a1().tone(80), [0xff, 0xbe, 0xc2, 0xff]);
-		assert_eq!(palette.a1().tone(70), [0xff, 0x9d, 0xa3, 0xff]);
-		assert_eq!(palette.a1().tone(60), [0xff, 0x7c, 0x84, 0xff]);
-		assert_eq!(palette.a1().tone(50), [0xff, 0x5a, 0x64, 0xff]);
-		assert_eq!(palette.a1().tone(40), [0xff, 0x34, 0x3d, 0xff]);
+		assert_eq!(palette.a1().tone(100), Argb::from(0xffffffff));
+		assert_eq!(palette.a1().tone(95), Argb::from(0xfff1efff));
+		assert_eq!(palette.a1().tone(90), Argb::from(0xffe0e0ff));
+		assert_eq!(palette.a1().tone(80), Argb::from(0xffbec2ff));
+		assert_eq!(palette.a1().tone(70), Argb::from(0xff9da3ff));
+		assert_eq!(palette.a1().tone(60), Argb::from(0xff7c84ff));
+		assert_eq!(palette.a1().tone(50), Argb::from(0xff5a64ff));
+		assert_eq!(palette.a1().tone(40), Argb::from(0xff343dff));
 		//NOTE:FIX: tone 30 of core palette is failling on green by 1 unit
-		//assert_eq!(palette.a1().tone(30), [0xff, 0x00, 0x00, 0xef]);
-		assert_eq!(palette.a1().tone(20), [0xff, 0x00, 0x01, 0xac]);
-		assert_eq!(palette.a1().tone(10), [0xff, 0x00, 0x00, 0x6e]);
-		assert_eq!(palette.a1().tone(0), [0xff, 0x00, 0x00, 0x00]);
+		//assert_eq!(palette.a1().tone(30), Argb::from(0xff0000ef));
+		assert_eq!(palette.a1().tone(20), Argb::from(0xff0001ac));
+		assert_eq!(palette.a1().tone(10), Argb::from(0xff00006e));
+		assert_eq!(palette.a1().tone(0), Argb::from(0xff000000));
 
-		assert_eq!(palette.a2().tone(100), [0xff, 0xff, 0xff, 0xff]);
-		assert_eq!(palette.a2().tone(95), [0xff, 0xf1, 0xef, 0xff]);
-		assert_eq!(palette.a2().tone(90), [0xff, 0xe1, 0xe0, 0xf9]);
-		assert_eq!(palette.a2().tone(80), [0xff, 0xc5, 0xc4, 0xdd]);
-		assert_eq!(palette.a2().tone(70), [0xff, 0xa9, 0xa9, 0xc1]);
-		assert_eq!(palette.a2().tone(60), [0xff, 0x8f, 0x8f, 0xa6]);
-		assert_eq!(palette.a2().tone(50), [0xff, 0x75, 0x75, 0x8b]);
-		assert_eq!(palette.a2().tone(40), [0xff, 0x5c, 0x5d, 0x72]);
-		assert_eq!(palette.a2().tone(30), [0xff, 0x44, 0x45, 0x59]);
-		assert_eq!(palette.a2().tone(20), [0xff, 0x2e, 0x2f, 0x42]);
-		assert_eq!(palette.a2().tone(10), [0xff, 0x19, 0x1a, 0x2c]);
-		assert_eq!(palette.a2().tone(0), [0xff, 0x00, 0x00, 0x00]);
+		assert_eq!(palette.a2().tone(100), Argb::from(0xffffffff));
+		assert_eq!(palette.a2().tone(95), Argb::from(0xfff1efff));
+		assert_eq!(palette.a2().tone(90), Argb::from(0xffe1e0f9));
+		assert_eq!(palette.a2().tone(80), Argb::from(0xffc5c4dd));
+		assert_eq!(palette.a2().tone(70), Argb::from(0xffa9a9c1));
+		assert_eq!(palette.a2().tone(60), Argb::from(0xff8f8fa6));
+		assert_eq!(palette.a2().tone(50), Argb::from(0xff75758b));
+		assert_eq!(palette.a2().tone(40), Argb::from(0xff5c5d72));
+		assert_eq!(palette.a2().tone(30), Argb::from(0xff444559));
+		assert_eq!(palette.a2().tone(20), Argb::from(0xff2e2f42));
+		assert_eq!(palette.a2().tone(10), Argb::from(0xff191a2c));
+		assert_eq!(palette.a2().tone(0), Argb::from(0xff000000));
 	}
 
 	#[test]
@@ -66,30 +66,30 @@ mod core {
 	fn of_content_blue() {
 		let mut palette = CorePalette::content_of(BLUE);
 
-		assert_eq!(palette.a1().tone(100), [0xff, 0xff, 0xff, 0xff]);
-		assert_eq!(palette.a1().tone(95), [0xff, 0xf1, 0xef, 0xff]);
-		assert_eq!(palette.a1().tone(90), [0xff, 0xe0, 0xe0, 0xff]);
-		assert_eq!(palette.a1().tone(80), [0xff, 0xbe, 0xc2, 0xff]);
-		assert_eq!(palette.a1().tone(70), [0xff, 0x9d, 0xa3, 0xff]);
-		assert_eq!(palette.a1().tone(60), [0xff, 0x7c, 0x84, 0xff]);
-		assert_eq!(palette.a1().tone(50), [0xff, 0x5a, 0x64, 0xff]);
-		assert_eq!(palette.a1().tone(40), [0xff, 0x34, 0x3d, 0xff]);
+		assert_eq!(palette.a1().tone(100), Argb::from(0xffffffff));
+		assert_eq!(palette.a1().tone(95), Argb::from(0xfff1efff));
+		assert_eq!(palette.a1().tone(90), Argb::from(0xffe0e0ff));
+		assert_eq!(palette.a1().tone(80), Argb::from(0xffbec2ff));
+		assert_eq!(palette.a1().tone(70), Argb::from(0xff9da3ff));
+		assert_eq!(palette.a1().tone(60), Argb::from(0xff7c84ff));
+		assert_eq!(palette.a1().tone(50), Argb::from(0xff5a64ff));
+		assert_eq!(palette.a1().tone(40), Argb::from(0xff343dff));
 		//NOTE:FIX: tone 30 of core palette is failling on green by 1 unit
-		//assert_eq!(palette.a1().tone(30), [0xff, 0x00, 0x00, 0xef]);
-		assert_eq!(palette.a1().tone(20), [0xff, 0x00, 0x01, 0xac]);
-		assert_eq!(palette.a1().tone(10), [0xff, 0x00, 0x00, 0x6e]);
-		assert_eq!(palette.a1().tone(0), [0xff, 0x00, 0x00, 0x00]);
-		assert_eq!(palette.a2().tone(100), [0xff, 0xff, 0xff, 0xff]);
-		assert_eq!(palette.a2().tone(95), [0xff, 0xf1, 0xef, 0xff]);
-		assert_eq!(palette.a2().tone(90), [0xff, 0xe0, 0xe0, 0xff]);
-		assert_eq!(palette.a2().tone(80), [0xff, 0xc1, 0xc3, 0xf4]);
-		assert_eq!(palette.a2().tone(70), [0xff, 0xa5, 0xa7, 0xd7]);
-		assert_eq!(palette.a2().tone(60), [0xff, 0x8b, 0x8d, 0xbb]);
-		assert_eq!(palette.a2().tone(50), [0xff, 0x71, 0x73, 0xa0]);
-		assert_eq!(palette.a2().tone(40), [0xff, 0x58, 0x5b, 0x86]);
-		assert_eq!(palette.a2().tone(30), [0xff, 0x40, 0x43, 0x6d]);
-		assert_eq!(palette.a2().tone(20), [0xff, 0x2a, 0x2d, 0x55]);
-		assert_eq!(palette.a2().tone(10), [0xff, 0x14, 0x17, 0x3f]);
-		assert_eq!(palette.a2().tone(0), [0xff, 0x00, 0x00, 0x00]);
+		//assert_eq!(palette.a1().tone(30), Argb::from(0xff0000ef));
+		assert_eq!(palette.a1().tone(20), Argb::from(0xff0001ac));
+		assert_eq!(palette.a1().tone(10), Argb::from(0xff00006e));
+		assert_eq!(palette.a1().tone(0), Argb::from(0xff000000));
+		assert_eq!(palette.a2().tone(100), Argb::from(0xffffffff));
+		assert_eq!(palette.a2().tone(95), Argb::from(0xfff1efff));
+		assert_eq!(palette.a2().tone(90), Argb::from(0xffe0e0ff));
+		assert_eq!(palette.a2().tone(80), Argb::from(0xffc1c3f4));
+		assert_eq!(palette.a2().tone(70), Argb::from(0xffa5a7d7));
+		assert_eq!(palette.a2().tone(60), Argb::from(0xff8b8dbb));
+		assert_eq!(palette.a2().tone(50), Argb::from(0xff7173a0));
+		assert_eq!(palette.a2().tone(40), Argb::from(0xff585b86));
+		assert_eq!(palette.a2().tone(30), Argb::from(0xff40436d));
+		assert_eq!(palette.a2().tone(20), Argb::from(0xff2a2d55));
+		assert_eq!(palette.a2().tone(10), Argb::from(0xff14173f));
+		assert_eq!(palette.a2().tone(0), Argb::from(0xff000000));
 	}
 }
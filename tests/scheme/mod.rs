@@ -1,180 +1,252 @@
 use super::consts::BLUE;
-use color_utilities::scheme::Scheme;
+use material_rs_color_utilities::{
+	scheme::Scheme,
+	utils::{
+		color::{lstar_from_argb, Argb},
+		contrast::ratio_of_tones,
+	},
+};
+
+fn contrast(a: Argb, b: Argb) -> f64 {
+	ratio_of_tones(lstar_from_argb(a), lstar_from_argb(b))
+}
 
 #[test]
 fn blue_light_scheme() {
 	let scheme = Scheme::light(BLUE);
-	assert_eq!(scheme.primary(), [0xff, 0x34, 0x3D, 0xFF])
+	assert_eq!(scheme.primary(), Argb::from(0xff343dff))
 }
 
 #[test]
 fn blue_dark_scheme() {
 	let scheme = Scheme::dark(BLUE);
-	assert_eq!(scheme.primary(), [0xff, 0xBE, 0xC2, 0xFF])
+	assert_eq!(scheme.primary(), Argb::from(0xffbec2ff))
+}
+
+#[test]
+fn light_high_contrast_scheme() {
+	let scheme = Scheme::light_high_contrast(BLUE);
+
+	assert!(contrast(scheme.primary(), scheme.surface()) >= 7.0);
+	assert!(contrast(scheme.on_primary(), scheme.primary()) >= 7.0);
+	assert!(contrast(scheme.on_primary_container(), scheme.primary_container()) >= 7.0);
+	assert!(contrast(scheme.on_surface_variant(), scheme.surface_variant()) >= 7.0);
+	assert!(contrast(scheme.outline(), scheme.surface()) >= 7.0);
+	assert!(contrast(scheme.inverse_on_surface(), scheme.inverse_surface()) >= 7.0);
+}
+
+#[test]
+fn dark_high_contrast_scheme() {
+	let scheme = Scheme::dark_high_contrast(BLUE);
+
+	assert!(contrast(scheme.primary(), scheme.surface()) >= 7.0);
+	assert!(contrast(scheme.on_primary(), scheme.primary()) >= 7.0);
+	assert!(contrast(scheme.on_error_container(), scheme.error_container()) >= 7.0);
+	assert!(contrast(scheme.on_surface_variant(), scheme.surface_variant()) >= 7.0);
+	assert!(contrast(scheme.outline(), scheme.surface()) >= 7.0);
+}
+
+#[test]
+fn medium_contrast_scheme_sits_between_standard_and_high() {
+	let standard = Scheme::light(BLUE);
+	let medium = Scheme::light_medium_contrast(BLUE);
+	let high = Scheme::light_high_contrast(BLUE);
+
+	let outline = |scheme: &Scheme| contrast(scheme.outline(), scheme.surface());
+	assert!(outline(&standard) < outline(&medium));
+	assert!(outline(&medium) < outline(&high));
+	assert_eq!(standard.surface(), medium.surface());
 }
 
 #[test]
 fn third_party_light_scheme() {
-	let scheme = Scheme::light([0xFF, 0x67, 0x50, 0xA4]);
+	let scheme = Scheme::light(Argb::from(0xff6750a4));
 
-	assert_eq!(scheme.primary(), [0xff, 0x67, 0x50, 0xA4]);
-	assert_eq!(scheme.secondary(), [0xff, 0x62, 0x5B, 0x71]);
-	assert_eq!(scheme.tertiary(), [0xff, 0x7E, 0x52, 0x60]);
-	assert_eq!(scheme.surface(), [0xff, 0xff, 0xfb, 0xff]);
-	assert_eq!(scheme.on_surface(), [0xff, 0x1c, 0x1b, 0x1e]);
+	assert_eq!(scheme.primary(), Argb::from(0xff6750a4));
+	assert_eq!(scheme.secondary(), Argb::from(0xff625b71));
+	assert_eq!(scheme.tertiary(), Argb::from(0xff7e5260));
+	assert_eq!(scheme.surface(), Argb::from(0xfffffbff));
+	assert_eq!(scheme.on_surface(), Argb::from(0xff1c1b1e));
 }
 
 #[test]
 fn third_party_dark_scheme() {
-	let scheme = Scheme::dark([0xff, 0x67, 0x50, 0xA4]);
+	let scheme = Scheme::dark(Argb::from(0xff6750a4));
 
-	assert_eq!(scheme.primary(), [0xff, 0xcf, 0xbc, 0xff]);
-	assert_eq!(scheme.secondary(), [0xff, 0xcb, 0xc2, 0xdb]);
-	assert_eq!(scheme.tertiary(), [0xff, 0xef, 0xb8, 0xc8]);
-	assert_eq!(scheme.surface(), [0xff, 0x1c, 0x1b, 0x1e]);
-	assert_eq!(scheme.on_surface(), [0xff, 0xe6, 0xe1, 0xe6]);
+	assert_eq!(scheme.primary(), Argb::from(0xffcfbcff));
+	assert_eq!(scheme.secondary(), Argb::from(0xffcbc2db));
+	assert_eq!(scheme.tertiary(), Argb::from(0xffefb8c8));
+	assert_eq!(scheme.surface(), Argb::from(0xff1c1b1e));
+	assert_eq!(scheme.on_surface(), Argb::from(0xffe6e1e6));
 }
 
 #[test]
 #[ignore = "fails sometimes by 3 or 1 units"]
 fn light_scheme_from_high_chroma_color() {
-	let scheme = Scheme::light([0xff, 0xfa, 0x2b, 0xec]);
-
-	assert_eq!(scheme.primary(), [0xff, 0xab, 0x00, 0xa2]);
-	assert_eq!(scheme.on_primary(), [0xff, 0xff, 0xff, 0xff]);
-	assert_eq!(scheme.primary_container(), [0xff, 0xff, 0xd7, 0xf3]);
-	assert_eq!(scheme.on_primary_container(), [0xff, 0x39, 0x00, 0x35]);
-	assert_eq!(scheme.secondary(), [0xff, 0x6e, 0x58, 0x68]);
-	assert_eq!(scheme.on_secondary(), [0xff, 0xff, 0xff, 0xff]);
-	assert_eq!(scheme.secondary_container(), [0xff, 0xf8, 0xda, 0xee]);
-	assert_eq!(scheme.on_secondary_container(), [0xff, 0x27, 0x16, 0x24]);
-	assert_eq!(scheme.tertiary(), [0xff, 0x81, 0x53, 0x43]);
-	assert_eq!(scheme.on_tertiary(), [0xff, 0xff, 0xff, 0xff]);
-	assert_eq!(scheme.tertiary_container(), [0xff, 0xff, 0xdb, 0xd0]);
-	//assert_eq!(scheme.on_tertiary_container(), [0xff, 0x32, 0x12, 0x07]);
-	assert_eq!(scheme.error(), [0xff, 0xba, 0x1a, 0x1a]);
-	assert_eq!(scheme.on_error(), [0xff, 0xff, 0xff, 0xff]);
-	assert_eq!(scheme.error_container(), [0xff, 0xff, 0xda, 0xd6]);
-	//assert_eq!(scheme.on_error_container(), [0xff, 0x41, 0x00, 0x02]);
-	assert_eq!(scheme.background(), [0xff, 0xff, 0xfb, 0xff]);
-	assert_eq!(scheme.on_background(), [0xff, 0x1f, 0x1a, 0x1d]);
-	assert_eq!(scheme.surface(), [0xff, 0xff, 0xfb, 0xff]);
-	assert_eq!(scheme.on_surface(), [0xff, 0x1f, 0x1a, 0x1d]);
-	assert_eq!(scheme.surface_variant(), [0xff, 0xee, 0xde, 0xe7]);
-	assert_eq!(scheme.on_surface_variant(), [0xff, 0x4e, 0x44, 0x4b]);
-	assert_eq!(scheme.outline(), [0xff, 0x80, 0x74, 0x7b]);
-	assert_eq!(scheme.outline_variant(), [0xff, 0xd2, 0xc2, 0xcb]);
-	assert_eq!(scheme.shadow(), [0xff, 0x00, 0x00, 0x00]);
-	assert_eq!(scheme.scrim(), [0xff, 0x00, 0x00, 0x00]);
-	assert_eq!(scheme.inverse_surface(), [0xff, 0x34, 0x2f, 0x32]);
-	assert_eq!(scheme.inverse_on_surface(), [0xff, 0xf8, 0xee, 0xf2]);
-	assert_eq!(scheme.inverse_primary(), [0xff, 0xff, 0xab, 0xee]);
+	let scheme = Scheme::light(Argb::from(0xfffa2bec));
+
+	assert_eq!(scheme.primary(), Argb::from(0xffab00a2));
+	assert_eq!(scheme.on_primary(), Argb::from(0xffffffff));
+	assert_eq!(scheme.primary_container(), Argb::from(0xffffd7f3));
+	assert_eq!(scheme.on_primary_container(), Argb::from(0xff390035));
+	assert_eq!(scheme.secondary(), Argb::from(0xff6e5868));
+	assert_eq!(scheme.on_secondary(), Argb::from(0xffffffff));
+	assert_eq!(scheme.secondary_container(), Argb::from(0xfff8daee));
+	assert_eq!(scheme.on_secondary_container(), Argb::from(0xff271624));
+	assert_eq!(scheme.tertiary(), Argb::from(0xff815343));
+	assert_eq!(scheme.on_tertiary(), Argb::from(0xffffffff));
+	assert_eq!(scheme.tertiary_container(), Argb::from(0xffffdbd0));
+	//assert_eq!(scheme.on_tertiary_container(), Argb::from(0xff321207));
+	assert_eq!(scheme.error(), Argb::from(0xffba1a1a));
+	assert_eq!(scheme.on_error(), Argb::from(0xffffffff));
+	assert_eq!(scheme.error_container(), Argb::from(0xffffdad6));
+	//assert_eq!(scheme.on_error_container(), Argb::from(0xff410002));
+	assert_eq!(scheme.background(), Argb::from(0xfffffbff));
+	assert_eq!(scheme.on_background(), Argb::from(0xff1f1a1d));
+	assert_eq!(scheme.surface(), Argb::from(0xfffffbff));
+	assert_eq!(scheme.on_surface(), Argb::from(0xff1f1a1d));
+	assert_eq!(scheme.surface_variant(), Argb::from(0xffeedee7));
+	assert_eq!(scheme.on_surface_variant(), Argb::from(0xff4e444b));
+	assert_eq!(scheme.outline(), Argb::from(0xff80747b));
+	assert_eq!(scheme.outline_variant(), Argb::from(0xffd2c2cb));
+	assert_eq!(scheme.shadow(), Argb::from(0xff000000));
+	assert_eq!(scheme.scrim(), Argb::from(0xff000000));
+	assert_eq!(scheme.inverse_surface(), Argb::from(0xff342f32));
+	assert_eq!(scheme.inverse_on_surface(), Argb::from(0xfff8eef2));
+	assert_eq!(scheme.inverse_primary(), Argb::from(0xffffabee));
 }
 
 #[test]
 #[ignore = "fails on some error roles by 3 or 5 units"]
 fn dark_scheme_from_high_chroma_color() {
-	let scheme = Scheme::dark([0xff, 0xfa, 0x2b, 0xec]);
-
-	assert_eq!(scheme.primary(), [0xff, 0xff, 0xab, 0xee]);
-	assert_eq!(scheme.on_primary(), [0xff, 0x5c, 0x00, 0x57]);
-	assert_eq!(scheme.primary_container(), [0xff, 0x83, 0x00, 0x7b]);
-	assert_eq!(scheme.on_primary_container(), [0xff, 0xff, 0xd7, 0xf3]);
-	assert_eq!(scheme.secondary(), [0xff, 0xdb, 0xbe, 0xd1]);
-	assert_eq!(scheme.on_secondary(), [0xff, 0x3e, 0x2a, 0x39]);
-	assert_eq!(scheme.secondary_container(), [0xff, 0x55, 0x40, 0x50]);
-	assert_eq!(scheme.on_secondary_container(), [0xff, 0xf8, 0xda, 0xee]);
-	assert_eq!(scheme.tertiary(), [0xff, 0xf5, 0xb9, 0xa5]);
-	assert_eq!(scheme.on_tertiary(), [0xff, 0x4c, 0x26, 0x19]);
-	assert_eq!(scheme.tertiary_container(), [0xff, 0x66, 0x3c, 0x2d]);
-	assert_eq!(scheme.on_tertiary_container(), [0xff, 0xff, 0xdb, 0xd0]);
-	assert_eq!(scheme.error(), [0xff, 0xff, 0xb4, 0xab]);
-	//assert_eq!(scheme.on_error(), [0xff, 0x69, 0x00, 0x05]);
-	//assert_eq!(scheme.error_container(), [0xff, 0x93, 0x00, 0x0a]);
-	assert_eq!(scheme.on_error_container(), [0xff, 0xff, 0xb4, 0xab]);
-	assert_eq!(scheme.background(), [0xff, 0x1f, 0x1a, 0x1d]);
-	assert_eq!(scheme.on_background(), [0xff, 0xea, 0xe0, 0xe4]);
-	assert_eq!(scheme.surface(), [0xff, 0x1f, 0x1a, 0x1d]);
-	assert_eq!(scheme.on_surface(), [0xff, 0xea, 0xe0, 0xe4]);
-	assert_eq!(scheme.surface_variant(), [0xff, 0x4e, 0x44, 0x4b]);
-	assert_eq!(scheme.on_surface_variant(), [0xff, 0xd2, 0xc2, 0xcb]);
-	assert_eq!(scheme.outline(), [0xff, 0x9a, 0x8d, 0x95]);
-	assert_eq!(scheme.outline_variant(), [0xff, 0x4e, 0x44, 0x4b]);
-	assert_eq!(scheme.shadow(), [0xff, 0x00, 0x00, 0x00]);
-	assert_eq!(scheme.scrim(), [0xff, 0x00, 0x00, 0x00]);
-	assert_eq!(scheme.inverse_surface(), [0xff, 0xea, 0xe0, 0xe4]);
-	assert_eq!(scheme.inverse_on_surface(), [0xff, 0x34, 0x2f, 0x32]);
-	assert_eq!(scheme.inverse_primary(), [0xff, 0xab, 0x00, 0xa2]);
+	let scheme = Scheme::dark(Argb::from(0xfffa2bec));
+
+	assert_eq!(scheme.primary(), Argb::from(0xffffabee));
+	assert_eq!(scheme.on_primary(), Argb::from(0xff5c0057));
+	assert_eq!(scheme.primary_container(), Argb::from(0xff83007b));
+	assert_eq!(scheme.on_primary_container(), Argb::from(0xffffd7f3));
+	assert_eq!(scheme.secondary(), Argb::from(0xffdbbed1));
+	assert_eq!(scheme.on_secondary(), Argb::from(0xff3e2a39));
+	assert_eq!(scheme.secondary_container(), Argb::from(0xff554050));
+	assert_eq!(scheme.on_secondary_container(), Argb::from(0xfff8daee));
+	assert_eq!(scheme.tertiary(), Argb::from(0xfff5b9a5));
+	assert_eq!(scheme.on_tertiary(), Argb::from(0xff4c2619));
+	assert_eq!(scheme.tertiary_container(), Argb::from(0xff663c2d));
+	assert_eq!(scheme.on_tertiary_container(), Argb::from(0xffffdbd0));
+	assert_eq!(scheme.error(), Argb::from(0xffffb4ab));
+	//assert_eq!(scheme.on_error(), Argb::from(0xff690005));
+	//assert_eq!(scheme.error_container(), Argb::from(0xff93000a));
+	assert_eq!(scheme.on_error_container(), Argb::from(0xffffb4ab));
+	assert_eq!(scheme.background(), Argb::from(0xff1f1a1d));
+	assert_eq!(scheme.on_background(), Argb::from(0xffeae0e4));
+	assert_eq!(scheme.surface(), Argb::from(0xff1f1a1d));
+	assert_eq!(scheme.on_surface(), Argb::from(0xffeae0e4));
+	assert_eq!(scheme.surface_variant(), Argb::from(0xff4e444b));
+	assert_eq!(scheme.on_surface_variant(), Argb::from(0xffd2c2cb));
+	assert_eq!(scheme.outline(), Argb::from(0xff9a8d95));
+	assert_eq!(scheme.outline_variant(), Argb::from(0xff4e444b));
+	assert_eq!(scheme.shadow(), Argb::from(0xff000000));
+	assert_eq!(scheme.scrim(), Argb::from(0xff000000));
+	assert_eq!(scheme.inverse_surface(), Argb::from(0xffeae0e4));
+	assert_eq!(scheme.inverse_on_surface(), Argb::from(0xff342f32));
+	assert_eq!(scheme.inverse_primary(), Argb::from(0xffab00a2));
 }
 
 #[test]
 #[ignore = "fails on on_error_container by 1 unit on blue"]
 fn light_content_scheme_from_high_chroma_color() {
-	let scheme = Scheme::light_content([0xff, 0xfa, 0x2b, 0xec]);
-
-	assert_eq!(scheme.primary(), [0xff, 0xab, 0x00, 0xa2]);
-	assert_eq!(scheme.on_primary(), [0xff, 0xff, 0xff, 0xff]);
-	assert_eq!(scheme.primary_container(), [0xff, 0xff, 0xd7, 0xf3]);
-	assert_eq!(scheme.on_primary_container(), [0xff, 0x39, 0x00, 0x35]);
-	assert_eq!(scheme.secondary(), [0xff, 0x7f, 0x4e, 0x75]);
-	assert_eq!(scheme.on_secondary(), [0xff, 0xff, 0xff, 0xff]);
-	assert_eq!(scheme.secondary_container(), [0xff, 0xff, 0xd7, 0xf3]);
-	assert_eq!(scheme.on_secondary_container(), [0xff, 0x33, 0x0b, 0x2f]);
-	assert_eq!(scheme.tertiary(), [0xff, 0x9c, 0x43, 0x23]);
-	assert_eq!(scheme.on_tertiary(), [0xff, 0xff, 0xff, 0xff]);
-	assert_eq!(scheme.tertiary_container(), [0xff, 0xff, 0xdb, 0xd0]);
-	assert_eq!(scheme.on_tertiary_container(), [0xff, 0x39, 0x0c, 0x00]);
-	assert_eq!(scheme.error(), [0xff, 0xba, 0x1a, 0x1a]);
-	assert_eq!(scheme.on_error(), [0xff, 0xff, 0xff, 0xff]);
-	assert_eq!(scheme.error_container(), [0xff, 0xff, 0xda, 0xd6]);
-	//assert_eq!(scheme.on_error_container(), [0xff, 0x41, 0x00, 0x02]);
-	assert_eq!(scheme.background(), [0xff, 0xff, 0xfb, 0xff]);
-	assert_eq!(scheme.on_background(), [0xff, 0x1f, 0x1a, 0x1d]);
-	assert_eq!(scheme.surface(), [0xff, 0xff, 0xfb, 0xff]);
-	assert_eq!(scheme.on_surface(), [0xff, 0x1f, 0x1a, 0x1d]);
-	assert_eq!(scheme.surface_variant(), [0xff, 0xee, 0xde, 0xe7]);
-	assert_eq!(scheme.on_surface_variant(), [0xff, 0x4e, 0x44, 0x4b]);
-	assert_eq!(scheme.outline(), [0xff, 0x80, 0x74, 0x7b]);
-	assert_eq!(scheme.outline_variant(), [0xff, 0xd2, 0xc2, 0xcb]);
-	assert_eq!(scheme.shadow(), [0xff, 0x00, 0x00, 0x00]);
-	assert_eq!(scheme.scrim(), [0xff, 0x00, 0x00, 0x00]);
-	assert_eq!(scheme.inverse_surface(), [0xff, 0x34, 0x2f, 0x32]);
-	assert_eq!(scheme.inverse_on_surface(), [0xff, 0xf8, 0xee, 0xf2]);
-	assert_eq!(scheme.inverse_primary(), [0xff, 0xff, 0xab, 0xee]);
+	let scheme = Scheme::light_content(Argb::from(0xfffa2bec));
+
+	assert_eq!(scheme.primary(), Argb::from(0xffab00a2));
+	assert_eq!(scheme.on_primary(), Argb::from(0xffffffff));
+	assert_eq!(scheme.primary_container(), Argb::from(0xffffd7f3));
+	assert_eq!(scheme.on_primary_container(), Argb::from(0xff390035));
+	assert_eq!(scheme.secondary(), Argb::from(0xff7f4e75));
+	assert_eq!(scheme.on_secondary(), Argb::from(0xffffffff));
+	assert_eq!(scheme.secondary_container(), Argb::from(0xffffd7f3));
+	assert_eq!(scheme.on_secondary_container(), Argb::from(0xff330b2f));
+	assert_eq!(scheme.tertiary(), Argb::from(0xff9c4323));
+	assert_eq!(scheme.on_tertiary(), Argb::from(0xffffffff));
+	assert_eq!(scheme.tertiary_container(), Argb::from(0xffffdbd0));
+	assert_eq!(scheme.on_tertiary_container(), Argb::from(0xff390c00));
+	assert_eq!(scheme.error(), Argb::from(0xffba1a1a));
+	assert_eq!(scheme.on_error(), Argb::from(0xffffffff));
+	assert_eq!(scheme.error_container(), Argb::from(0xffffdad6));
+	//assert_eq!(scheme.on_error_container(), Argb::from(0xff410002));
+	assert_eq!(scheme.background(), Argb::from(0xfffffbff));
+	assert_eq!(scheme.on_background(), Argb::from(0xff1f1a1d));
+	assert_eq!(scheme.surface(), Argb::from(0xfffffbff));
+	assert_eq!(scheme.on_surface(), Argb::from(0xff1f1a1d));
+	assert_eq!(scheme.surface_variant(), Argb::from(0xffeedee7));
+	assert_eq!(scheme.on_surface_variant(), Argb::from(0xff4e444b));
+	assert_eq!(scheme.outline(), Argb::from(0xff80747b));
+	assert_eq!(scheme.outline_variant(), Argb::from(0xffd2c2cb));
+	assert_eq!(scheme.shadow(), Argb::from(0xff000000));
+	assert_eq!(scheme.scrim(), Argb::from(0xff000000));
+	assert_eq!(scheme.inverse_surface(), Argb::from(0xff342f32));
+	assert_eq!(scheme.inverse_on_surface(), Argb::from(0xfff8eef2));
+	assert_eq!(scheme.inverse_primary(), Argb::from(0xffffabee));
 }
 
 #[test]
 #[ignore = "fails on some error roles by 3 or 5 units"]
 fn dark_content_scheme_from_high_chroma_color() {
-	let scheme = Scheme::dark_content([0xff, 0xfa, 0x2b, 0xec]);
-
-	assert_eq!(scheme.primary(), [0xff, 0xff, 0xab, 0xee]);
-	assert_eq!(scheme.on_primary(), [0xff, 0x5c, 0x00, 0x57]);
-	assert_eq!(scheme.primary_container(), [0xff, 0x83, 0x00, 0x7b]);
-	assert_eq!(scheme.on_primary_container(), [0xff, 0xff, 0xd7, 0xf3]);
-	assert_eq!(scheme.secondary(), [0xff, 0xf0, 0xb4, 0xe1]);
-	assert_eq!(scheme.on_secondary(), [0xff, 0x4b, 0x21, 0x45]);
-	assert_eq!(scheme.secondary_container(), [0xff, 0x64, 0x37, 0x5c]);
-	assert_eq!(scheme.on_secondary_container(), [0xff, 0xff, 0xd7, 0xf3]);
-	assert_eq!(scheme.tertiary(), [0xff, 0xff, 0xb5, 0x9c]);
-	assert_eq!(scheme.on_tertiary(), [0xff, 0x5c, 0x19, 0x00]);
-	assert_eq!(scheme.tertiary_container(), [0xff, 0x7d, 0x2c, 0x0d]);
-	assert_eq!(scheme.on_tertiary_container(), [0xff, 0xff, 0xdb, 0xd0]);
-	assert_eq!(scheme.error(), [0xff, 0xff, 0xb4, 0xab]);
-	//assert_eq!(scheme.on_error(), [0xff, 0x69, 0x00, 0x05]);
-	//assert_eq!(scheme.error_container(), [0xff, 0x93, 0x00, 0x0a]);
-	assert_eq!(scheme.on_error_container(), [0xff, 0xff, 0xb4, 0xab]);
-	assert_eq!(scheme.background(), [0xff, 0x1f, 0x1a, 0x1d]);
-	assert_eq!(scheme.on_background(), [0xff, 0xea, 0xe0, 0xe4]);
-	assert_eq!(scheme.surface(), [0xff, 0x1f, 0x1a, 0x1d]);
-	assert_eq!(scheme.on_surface(), [0xff, 0xea, 0xe0, 0xe4]);
-	assert_eq!(scheme.surface_variant(), [0xff, 0x4e, 0x44, 0x4b]);
-	assert_eq!(scheme.on_surface_variant(), [0xff, 0xd2, 0xc2, 0xcb]);
-	assert_eq!(scheme.outline(), [0xff, 0x9a, 0x8d, 0x95]);
-	assert_eq!(scheme.outline_variant(), [0xff, 0x4e, 0x44, 0x4b]);
-	assert_eq!(scheme.shadow(), [0xff, 0x00, 0x00, 0x00]);
-	assert_eq!(scheme.scrim(), [0xff, 0x00, 0x00, 0x00]);
-	assert_eq!(scheme.inverse_surface(), [0xff, 0xea, 0xe0, 0xe4]);
-	assert_eq!(scheme.inverse_on_surface(), [0xff, 0x34, 0x2f, 0x32]);
-	assert_eq!(scheme.inverse_primary(), [0xff, 0xab, 0x00, 0xa2]);
+	let scheme = Scheme::dark_content(Argb::from(0xfffa2bec));
+
+	assert_eq!(scheme.primary(), Argb::from(0xffffabee));
+	assert_eq!(scheme.on_primary(), Argb::from(0xff5c0057));
+	assert_eq!(scheme.primary_container(), Argb::from(0xff83007b));
+	assert_eq!(scheme.on_primary_container(), Argb::from(0xffffd7f3));
+	assert_eq!(scheme.secondary(), Argb::from(0xfff0b4e1));
+	assert_eq!(scheme.on_secondary(), Argb::from(0xff4b2145));
+	assert_eq!(scheme.secondary_container(), Argb::from(0xff64375c));
+	assert_eq!(scheme.on_secondary_container(), Argb::from(0xffffd7f3));
+	assert_eq!(scheme.tertiary(), Argb::from(0xffffb59c));
+	assert_eq!(scheme.on_tertiary(), Argb::from(0xff5c1900));
+	assert_eq!(scheme.tertiary_container(), Argb::from(0xff7d2c0d));
+	assert_eq!(scheme.on_tertiary_container(), Argb::from(0xffffdbd0));
+	assert_eq!(scheme.error(), Argb::from(0xffffb4ab));
+	//assert_eq!(scheme.on_error(), Argb::from(0xff690005));
+	//assert_eq!(scheme.error_container(), Argb::from(0xff93000a));
+	assert_eq!(scheme.on_error_container(), Argb::from(0xffffb4ab));
+	assert_eq!(scheme.background(), Argb::from(0xff1f1a1d));
+	assert_eq!(scheme.on_background(), Argb::from(0xffeae0e4));
+	assert_eq!(scheme.surface(), Argb::from(0xff1f1a1d));
+	assert_eq!(scheme.on_surface(), Argb::from(0xffeae0e4));
+	assert_eq!(scheme.surface_variant(), Argb::from(0xff4e444b));
+	assert_eq!(scheme.on_surface_variant(), Argb::from(0xffd2c2cb));
+	assert_eq!(scheme.outline(), Argb::from(0xff9a8d95));
+	assert_eq!(scheme.outline_variant(), Argb::from(0xff4e444b));
+	assert_eq!(scheme.shadow(), Argb::from(0xff000000));
+	assert_eq!(scheme.scrim(), Argb::from(0xff000000));
+	assert_eq!(scheme.inverse_surface(), Argb::from(0xffeae0e4));
+	assert_eq!(scheme.inverse_on_surface(), Argb::from(0xff342f32));
+	assert_eq!(scheme.inverse_primary(), Argb::from(0xffab00a2));
+}
+
+#[test]
+fn roles_match_getters() {
+	let scheme = Scheme::dark(BLUE);
+	let roles = scheme.roles();
+
+	assert_eq!(roles[0], ("primary", scheme.primary()));
+	assert_eq!(roles[12], ("error", scheme.error()));
+	assert_eq!(roles[28], ("inverse_primary", scheme.inverse_primary()));
+	assert_eq!(roles.map(|(role, _)| role), Scheme::ROLE_NAMES);
+}
+
+#[test]
+fn from_roles() {
+	let scheme = Scheme::light(BLUE);
+	let roles = scheme.roles();
+	let lookup = |name| {
+		roles
+			.iter()
+			.find(|(role, _)| *role == name)
+			.map(|(_, argb)| *argb)
+			.ok_or(name)
+	};
+
+	assert_eq!(Scheme::from_roles(lookup), Ok(scheme));
+	assert_eq!(Scheme::from_roles(Err::<Argb, _>), Err("primary"));
 }
@@ -0,0 +1,73 @@
+use super::consts::*;
+use material_rs_color_utilities::{
+	hct::Hct,
+	palettes::{CorePalette, TonalPalette},
+	scheme::Scheme,
+	utils::color::Argb,
+};
+use serde_json::json;
+
+#[test]
+fn argb_as_hex() {
+	assert_eq!(serde_json::to_value(BLUE).unwrap(), json!("#0000ff"));
+	assert_eq!(
+		serde_json::to_value(Argb::from(0x806750a4)).unwrap(),
+		json!("#806750a4")
+	);
+	assert_eq!(serde_json::from_value::<Argb>(json!("#f00")).unwrap(), RED);
+	assert!(serde_json::from_value::<Argb>(json!("red")).is_err());
+	assert!(serde_json::from_value::<Argb>(json!(0xffff0000u32)).is_err());
+}
+
+#[test]
+fn scheme_as_role_map() {
+	let scheme = Scheme::light(BLUE);
+	let value = serde_json::to_value(&scheme).unwrap();
+
+	assert_eq!(value.as_object().unwrap().len(), 29);
+	assert_eq!(value["primary"], json!("#343dff"));
+	assert_eq!(value["on_primary"], json!("#ffffff"));
+	assert_eq!(serde_json::from_value::<Scheme>(value).unwrap(), scheme);
+}
+
+#[test]
+fn hct_roundtrip() {
+	let hct = Hct::from_argb(Argb::from(0xff6750a4));
+	let json = serde_json::to_string(&hct).unwrap();
+
+	assert_eq!(serde_json::from_str::<Hct>(&json).unwrap(), hct);
+}
+
+#[test]
+fn hct_without_argb_is_solved() {
+	let hct: Hct =
+		serde_json::from_value(json!({ "hue": 120.0, "chroma": 40.0, "tone": 50.0 })).unwrap();
+
+	assert_eq!(hct, Hct::from(120.0, 40.0, 50.0));
+}
+
+#[test]
+fn tonal_palette_keeps_cached_tones() {
+	let mut palette = TonalPalette::from_argb(BLUE);
+	palette.tone(40);
+	palette.tone(90);
+	let value = serde_json::to_value(&palette).unwrap();
+
+	assert_eq!(
+		value["tones"],
+		json!({ "40": palette.tone(40), "90": palette.tone(90) })
+	);
+	assert_eq!(
+		serde_json::from_value::<TonalPalette>(value).unwrap(),
+		palette
+	);
+}
+
+#[test]
+fn core_palette_roundtrip() {
+	let mut core = CorePalette::of(RED);
+	core.a1().tone(40);
+	let json = serde_json::to_string(&core).unwrap();
+
+	assert_eq!(serde_json::from_str::<CorePalette>(&json).unwrap(), core);
+}
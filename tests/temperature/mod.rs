@@ -0,0 +1,103 @@
+use super::consts::*;
+use assert_approx_eq::assert_approx_eq;
+use material_rs_color_utilities::{hct::Hct, temperature::TemperatureCache, utils::color::Argb};
+
+// The solver drifts by a few units on some channels from upstream, so colors
+// are compared by hue, which is what the cache picks.
+fn assert_same_hues(actual: &[Hct], expected: &[Argb]) {
+	assert_eq!(actual.len(), expected.len());
+	for (actual, expected) in actual.iter().zip(expected) {
+		assert_approx_eq!(actual.hue(), Hct::from_argb(*expected).hue(), 2.0);
+	}
+}
+
+#[test]
+fn raw_temperature() {
+	assert_approx_eq!(
+		TemperatureCache::raw_temperature(&Hct::from_argb(BLUE)),
+		-1.393,
+		0.001
+	);
+	assert_approx_eq!(
+		TemperatureCache::raw_temperature(&Hct::from_argb(RED)),
+		2.351,
+		0.001
+	);
+	assert_approx_eq!(
+		TemperatureCache::raw_temperature(&Hct::from_argb(GREEN)),
+		-0.267,
+		0.001
+	);
+	assert_approx_eq!(
+		TemperatureCache::raw_temperature(&Hct::from_argb(WHITE)),
+		-0.5,
+		0.001
+	);
+	assert_approx_eq!(
+		TemperatureCache::raw_temperature(&Hct::from_argb(BLACK)),
+		-0.5,
+		0.001
+	);
+}
+
+#[test]
+fn relative_temperature() {
+	let relative = |argb| TemperatureCache::new(Hct::from_argb(argb)).input_relative_temperature();
+
+	assert_approx_eq!(relative(BLUE), 0.0, 0.001);
+	assert_approx_eq!(relative(RED), 1.0, 0.001);
+	assert_approx_eq!(relative(GREEN), 0.467, 0.001);
+	assert_approx_eq!(relative(WHITE), 0.5, 0.001);
+	assert_approx_eq!(relative(BLACK), 0.5, 0.001);
+}
+
+#[test]
+fn complement() {
+	let complement = |argb| [TemperatureCache::new(Hct::from_argb(argb)).complement()];
+
+	assert_same_hues(&complement(BLUE), &[Argb::from(0xff9d0002)]);
+	assert_same_hues(&complement(RED), &[Argb::from(0xff007bfc)]);
+	assert_same_hues(&complement(GREEN), &[Argb::from(0xffffd2c9)]);
+	assert_eq!(complement(WHITE)[0].to_int(), WHITE);
+	assert_eq!(complement(BLACK)[0].to_int(), BLACK);
+}
+
+#[test]
+fn analogous() {
+	let analogous = |argb| TemperatureCache::new(Hct::from_argb(argb)).analogous(5, 12);
+
+	assert_same_hues(
+		&analogous(BLUE),
+		&[
+			Argb::from(0xff00590c),
+			Argb::from(0xff00564e),
+			BLUE,
+			Argb::from(0xff6700cc),
+			Argb::from(0xff81009f),
+		],
+	);
+	assert_same_hues(
+		&analogous(RED),
+		&[
+			Argb::from(0xfff60082),
+			Argb::from(0xfffc004c),
+			RED,
+			Argb::from(0xffd95500),
+			Argb::from(0xffaf7200),
+		],
+	);
+	assert_same_hues(
+		&analogous(GREEN),
+		&[
+			Argb::from(0xffcee900),
+			Argb::from(0xff92f500),
+			GREEN,
+			Argb::from(0xff00fd6f),
+			Argb::from(0xff00fab3),
+		],
+	);
+	assert!(analogous(BLACK).iter().all(|hct| hct.to_int() == BLACK));
+	assert!(analogous(WHITE).iter().all(|hct| hct.to_int() == WHITE));
+	assert_eq!(analogous(BLUE).len(), 5);
+	assert_eq!(analogous(BLUE)[2], Hct::from_argb(BLUE));
+}
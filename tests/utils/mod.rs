@@ -0,0 +1,164 @@
+use super::consts::*;
+use material_rs_color_utilities::utils::color::Argb;
+
+mod argb {
+	use super::*;
+	use std::collections::HashSet;
+
+	#[test]
+	fn packing() {
+		let argb = Argb::from_rgba(0x12, 0x34, 0x56, 0x78);
+
+		assert_eq!(u32::from(argb), 0x78123456);
+		assert_eq!(Argb::from(0x78123456), argb);
+		assert_eq!(<[u8; 4]>::from(argb), [0x78, 0x12, 0x34, 0x56]);
+		assert_eq!(Argb::from([0x78, 0x12, 0x34, 0x56]), argb);
+	}
+
+	#[test]
+	fn components() {
+		let argb = Argb::from(0x80ff7f00);
+
+		assert_eq!(argb.alpha(), 0x80);
+		assert_eq!(argb.red(), 0xff);
+		assert_eq!(argb.green(), 0x7f);
+		assert_eq!(argb.blue(), 0x00);
+		assert!(!argb.is_opaque());
+		assert!(RED.is_opaque());
+	}
+
+	#[test]
+	#[allow(deprecated)]
+	fn numeric_components() {
+		use material_rs_color_utilities::utils::color::{
+			alpha_from_argb_numeric, blue_from_argb_numeric, green_from_argb_numeric,
+			red_from_argb_numeric, ARGB,
+		};
+
+		assert_eq!(alpha_from_argb_numeric(2164227840.0), 0x80);
+		assert_eq!(red_from_argb_numeric(2164227840.0), 0xff);
+		assert_eq!(green_from_argb_numeric(2164227840.0), 0x7f);
+		assert_eq!(blue_from_argb_numeric(2164227840.0), 0x00);
+
+		let argb: ARGB = Argb::from(0x80ff7f00);
+		assert_eq!(argb, Argb::from_rgba(0xff, 0x7f, 0x00, 0x80));
+	}
+
+	#[test]
+	fn formatting() {
+		assert_eq!(BLUE.to_string(), "#0000ff");
+		assert_eq!(Argb::from(0x80ff7f00).to_string(), "#80ff7f00");
+		assert_eq!(format!("{:x}", BLUE), "ff0000ff");
+		assert_eq!(format!("{:#010X}", GREEN), "0xFF00FF00");
+	}
+
+	#[test]
+	fn ordering_and_hashing() {
+		assert!(BLACK < BLUE);
+		assert!(BLUE < GREEN);
+		assert!(GREEN < RED);
+		assert!(RED < WHITE);
+
+		let set: HashSet<Argb> = [RED, GREEN, RED].into_iter().collect();
+		assert_eq!(set.len(), 2);
+	}
+}
+
+mod string {
+	use super::*;
+	use material_rs_color_utilities::utils::string::{
+		argb_from_css, argb_from_hex, argb_from_name, css_from_argb, hex_from_argb, ParseColorError,
+	};
+
+	#[test]
+	fn hex() {
+		assert_eq!(argb_from_hex("#00f"), Ok(BLUE));
+		assert_eq!(argb_from_hex("8f00"), Ok(Argb::from(0x88ff0000)));
+		assert_eq!(argb_from_hex("#00FF00"), Ok(GREEN));
+		assert_eq!(argb_from_hex("80ffffff"), Ok(Argb::from(0x80ffffff)));
+		assert_eq!(argb_from_hex("#12345"), Err(ParseColorError::InvalidHex));
+		assert_eq!(argb_from_hex("#gggggg"), Err(ParseColorError::InvalidHex));
+		assert_eq!(argb_from_hex(""), Err(ParseColorError::InvalidHex));
+		assert_eq!(argb_from_hex("##fff"), Err(ParseColorError::InvalidHex));
+	}
+
+	#[test]
+	fn hex_roundtrip() {
+		for argb in [RED, BLACK, Argb::from(0x806750a4), Argb::from(0x00000000)] {
+			assert_eq!(argb_from_hex(&hex_from_argb(argb)), Ok(argb));
+			assert_eq!(argb.to_string().parse(), Ok(argb));
+		}
+		assert_eq!(hex_from_argb(Argb::from(0xff6750a4)), "#6750a4");
+	}
+
+	#[test]
+	fn css_hex_has_alpha_last() {
+		assert_eq!(argb_from_css("#f008"), Ok(Argb::from(0x88ff0000)));
+		assert_eq!(argb_from_css("#ff000080"), Ok(Argb::from(0x80ff0000)));
+		assert_eq!(argb_from_css("#6750A4"), Ok(Argb::from(0xff6750a4)));
+		assert_eq!(argb_from_css("##fff"), Err(ParseColorError::InvalidHex));
+		assert_eq!(argb_from_css("##ffffff"), Err(ParseColorError::InvalidHex));
+		assert_eq!(css_from_argb(Argb::from(0x80ff0000)), "#ff000080");
+		assert_eq!(css_from_argb(RED), "#ff0000");
+	}
+
+	#[test]
+	fn css_rgb() {
+		assert_eq!(argb_from_css("rgb(255, 0, 0)"), Ok(RED));
+		assert_eq!(argb_from_css("rgb(0 255 0)"), Ok(GREEN));
+		assert_eq!(argb_from_css("RGB(0%, 0%, 100%)"), Ok(BLUE));
+		assert_eq!(
+			argb_from_css("rgba(255, 255, 255, 0.5)"),
+			Ok(Argb::from(0x80ffffff))
+		);
+		assert_eq!(
+			argb_from_css("rgb(0 0 0 / 50%)"),
+			Ok(Argb::from(0x80000000))
+		);
+		assert_eq!(argb_from_css("rgb(300, -5, 0)"), Ok(RED));
+		assert_eq!(
+			argb_from_css("rgb(255, 0)"),
+			Err(ParseColorError::InvalidFunction)
+		);
+		assert_eq!(
+			argb_from_css("rgb(a, b, c)"),
+			Err(ParseColorError::InvalidFunction)
+		);
+		assert_eq!(
+			argb_from_css("lab(50 0 0)"),
+			Err(ParseColorError::InvalidFunction)
+		);
+	}
+
+	#[test]
+	fn css_hsl() {
+		assert_eq!(argb_from_css("hsl(0, 100%, 50%)"), Ok(RED));
+		assert_eq!(argb_from_css("hsl(120deg 100% 50%)"), Ok(GREEN));
+		assert_eq!(argb_from_css("hsl(0.6666667turn, 100%, 50%)"), Ok(BLUE));
+		assert_eq!(argb_from_css("hsl(-120, 100%, 50%)"), Ok(BLUE));
+		assert_eq!(argb_from_css("hsl(0, 0%, 100%)"), Ok(WHITE));
+		assert_eq!(
+			argb_from_css("hsla(0, 0%, 0%, 0.5)"),
+			Ok(Argb::from(0x80000000))
+		);
+		assert_eq!(
+			argb_from_css("hsl(262, 35%, 48%)"),
+			Ok(Argb::from(0xff6f50a5))
+		);
+	}
+
+	#[test]
+	fn css_names() {
+		assert_eq!(argb_from_css("red"), Ok(RED));
+		assert_eq!(argb_from_css(" RebeccaPurple "), Ok(Argb::from(0xff663399)));
+		assert_eq!(argb_from_css("transparent"), Ok(Argb::from(0x00000000)));
+		assert_eq!(
+			argb_from_name("lightgoldenrodyellow"),
+			Some(Argb::from(0xfffafad2))
+		);
+		assert_eq!(
+			argb_from_css("materialpurple"),
+			Err(ParseColorError::UnknownName)
+		);
+	}
+}